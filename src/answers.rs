@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::fnv1a;
+use crate::runner::Part;
+
+const HASH_PREFIX: &str = "fnv1a:";
+
+/// A verified answer, optionally stored as hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    Hashed(u64),
+}
+
+impl Expected {
+    /// Hashes are salted with day and part, so equal answers of different
+    /// puzzles can't be told apart.
+    fn hash(day: u8, part: Part, answer: &str) -> u64 {
+        fnv1a(format!("{day}/{}/{answer}", part.number()).as_bytes())
+    }
+
+    pub fn new(day: u8, part: Part, answer: &str, hashed: bool) -> Self {
+        if hashed {
+            Expected::Hashed(Self::hash(day, part, answer))
+        } else {
+            Expected::Plain(answer.to_owned())
+        }
+    }
+
+    pub fn matches(&self, day: u8, part: Part, answer: &str) -> bool {
+        match self {
+            Expected::Plain(expected) => expected == answer,
+            Expected::Hashed(hash) => *hash == Self::hash(day, part, answer),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Plain(answer) => write!(f, "{answer}"),
+            Expected::Hashed(hash) => write!(f, "{HASH_PREFIX}{hash:016x}"),
+        }
+    }
+}
+
+/// Expected answers, one `<day> <part> <answer>` entry per line, where the
+/// answer is the rest of the line. Hashed answers are written as
+/// `fnv1a:<hex>`.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part), Expected>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+
+        for (idx, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = (|| {
+                let (day, rest) = line.split_once(char::is_whitespace)?;
+                let (part, answer) =
+                    rest.trim_start().split_once(char::is_whitespace)?;
+
+                let day = day.parse().ok()?;
+                let part = match part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return None,
+                };
+                let answer = answer.trim_start();
+                let expected = match answer.strip_prefix(HASH_PREFIX) {
+                    Some(hex) => {
+                        Expected::Hashed(u64::from_str_radix(hex, 16).ok()?)
+                    }
+                    None => Expected::Plain(answer.to_owned()),
+                };
+                Some(((day, part), expected))
+            })();
+
+            match entry {
+                Some((key, expected)) => {
+                    entries.insert(key, expected);
+                }
+                None => {
+                    return Err(format!("line {}: malformed entry", idx + 1));
+                }
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Expected> {
+        self.entries.get(&(day, part))
+    }
+
+    /// Whether `answer` is the expected one, `None` if nothing was recorded.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Option<bool> {
+        self.get(day, part)
+            .map(|expected| expected.matches(day, part, answer))
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, hashed: bool) {
+        self.entries
+            .insert((day, part), Expected::new(day, part, answer, hashed));
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part answer")?;
+
+        for ((day, part), expected) in &self.entries {
+            writeln!(f, "{day} {} {expected}", part.number())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_answers() {
+    let mut answers = Answers::parse(
+        "\
+# day part answer
+1 1 3
+1 2 6
+
+12 1 2",
+    )
+    .unwrap();
+
+    assert_eq!(
+        answers.get(1, Part::One),
+        Some(&Expected::Plain("3".into()))
+    );
+    assert_eq!(answers.check(1, Part::Two, "6"), Some(true));
+    assert_eq!(answers.check(12, Part::One, "3"), Some(false));
+    assert_eq!(answers.check(12, Part::Two, "2"), None);
+    assert!(Answers::parse("1 3 42").is_err());
+    assert!(Answers::parse("1 1").is_err());
+
+    answers.record(12, Part::One, "2", true);
+    answers.record(5, Part::Two, "two  words", false);
+    let answers = Answers::parse(&answers.to_string()).unwrap();
+    assert!(matches!(
+        answers.get(12, Part::One),
+        Some(Expected::Hashed(_))
+    ));
+    assert_eq!(answers.check(12, Part::One, "2"), Some(true));
+    assert_eq!(answers.check(12, Part::One, "3"), Some(false));
+    assert_eq!(answers.check(1, Part::One, "3"), Some(true));
+    assert_eq!(answers.check(5, Part::Two, "two  words"), Some(true));
+    assert_eq!(answers.check(5, Part::Two, "two"), Some(false));
+}
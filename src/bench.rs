@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parse::ParseError;
+use crate::runner::Part;
+use crate::{Answer, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// Unmeasured runs before sampling starts.
+    pub warmup: usize,
+    pub samples: usize,
+    /// Time after which a phase stops sampling early. At least one sample is
+    /// always taken.
+    pub budget: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 50,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part.number()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance =
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        Self {
+            samples: n,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+        }
+    }
+}
+
+fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    for _ in 0..config.warmup {
+        if start.elapsed() >= config.budget {
+            break;
+        }
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    let mut samples = vec![];
+    while samples.is_empty()
+        || (samples.len() < config.samples && start.elapsed() < config.budget)
+    {
+        let t = Instant::now();
+        black_box(f());
+        samples.push(t.elapsed());
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// Timings of the parse and solve phases of a single day.
+pub type Timings = Vec<(Phase, Stats)>;
+
+/// Benchmarks parsing and the selected parts of a single day. Parts without
+/// an answer or failing to compute one are left out.
+pub(crate) fn bench<S: Solution>(
+    input: &str,
+    part: Option<Part>,
+    config: &Config,
+) -> Result<Timings, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut res = vec![(Phase::Parse, measure(config, || S::parse(input)))];
+
+    if part != Some(Part::Two)
+        && S::part1(&parsed).render().is_ok_and(|a| a.is_some())
+    {
+        let stats = measure(config, || S::part1(&parsed));
+        res.push((Phase::Solve(Part::One), stats));
+    }
+
+    if part != Some(Part::One)
+        && S::part2(&parsed).render().is_ok_and(|a| a.is_some())
+    {
+        let stats = measure(config, || S::part2(&parsed));
+        res.push((Phase::Solve(Part::Two), stats));
+    }
+
+    Ok(res)
+}
+
+/// Saved timings, keyed by day and phase, e.g. `day04/part2`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: BTreeMap<String, Stats>,
+}
+
+impl Baseline {
+    fn key(day: u8, phase: Phase) -> String {
+        format!("day{day:02}/{phase}")
+    }
+
+    pub fn insert(&mut self, day: u8, phase: Phase, stats: Stats) {
+        self.entries.insert(Self::key(day, phase), stats);
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.entries.get(&Self::key(day, phase))
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+    }
+}
+
+/// Relative change of the median compared to `base`, e.g. `0.1` for 10%
+/// slower.
+pub fn change(base: &Stats, current: &Stats) -> f64 {
+    current.median.as_secs_f64() / base.median.as_secs_f64() - 1.0
+}
+
+/// Whether `current` is slower than `base` by more than `threshold`. Changes
+/// within the measured noise aren't counted.
+pub fn is_regression(base: &Stats, current: &Stats, threshold: f64) -> bool {
+    let noise = base.stddev.max(current.stddev);
+
+    change(base, current) > threshold && current.median > base.median + noise
+}
+
+/// Serializes durations as whole nanoseconds.
+pub(crate) mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        d: &Duration,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(d.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_nanos)
+    }
+}
+
+#[test]
+fn test_bench() {
+    let ms = Duration::from_millis;
+    let stats = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]);
+
+    assert_eq!(stats.samples, 4);
+    assert_eq!(stats.min, ms(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert!((stats.mean.as_secs_f64() - 0.0025).abs() < 1e-9);
+    assert_eq!(stats.stddev.as_micros(), 1118);
+
+    let mut baseline = Baseline::default();
+    baseline.insert(4, Phase::Solve(Part::Two), stats);
+    let json = serde_json::to_string(&baseline).unwrap();
+    let baseline: Baseline = serde_json::from_str(&json).unwrap();
+    let base = baseline.get(4, Phase::Solve(Part::Two)).unwrap();
+    assert_eq!(*base, stats);
+
+    let slower = Stats::from_samples(&[ms(6), ms(6), ms(6)]);
+    let noisy = Stats::from_samples(&[ms(1), ms(3), ms(10)]);
+    assert!(is_regression(base, &slower, 0.1));
+    assert!(!is_regression(base, &noisy, 0.1));
+    assert!(!is_regression(&slower, base, 0.1));
+}
@@ -0,0 +1,723 @@
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use aoc_2025::answers::Answers;
+use aoc_2025::bench::{self, Baseline};
+use aoc_2025::differential;
+use aoc_2025::generator;
+use aoc_2025::input::{self, Source};
+use aoc_2025::minimize::{self, Predicate};
+use aoc_2025::rng::Rng;
+use aoc_2025::runner::{self, DAYS, Day, Part, Run};
+
+const USAGE: &str = "\
+usage: aoc <command> [day...] [options]
+
+commands:
+    run      print the answers of the selected days
+    test     compare the answers against the answers file
+    record   store the current answers in the answers file
+    bench    time parsing and solving of the selected days
+    diff     compare solvers against reference implementations on random
+             inputs
+    generate print a random input for a single day
+    minimize shrink a failing input of a single day to a minimal reproducer
+
+options:
+    -p, --part <1|2>         only run the given part
+    -i, --input <path>       input file, `-` for stdin, requires a single day
+    -d, --input-dir <dir>    directory of the inputNN.txt files (default
+                             $AOC_INPUT_DIR or input/)
+    -f, --format <format>    output format: table (default), plain or json
+    -a, --answers <path>     answers file (default answers.txt in the input
+                             directory)
+
+record options:
+    --hashed                 store hashes instead of plain answers
+
+bench options:
+    -n, --samples <n>        measured runs per phase (default 50)
+    -w, --warmup <n>         unmeasured runs per phase (default 3)
+    -b, --budget <secs>      time after which a phase stops sampling
+                             (default 5)
+    --save <path>            save the timings as JSON baseline
+    --baseline <path>        compare the timings against a saved baseline
+    --threshold <percent>    slowdown of the median reported as regression
+                             (default 10)
+
+diff options:
+    --seed <n>               seed of the first case (default random)
+    --cases <n>              random inputs per day (default 100)
+    --size <n>               size of the largest input (default 8)
+
+generate options:
+    --seed <n>               seed of the input (default random)
+    --size <n>               size of the input, see the generator of the day
+                             (default 8)
+    -o, --output <path>      write the input to a file instead of stdout
+
+minimize options:
+    --panic                  keep inputs that make the solver panic (default)
+    --mismatch               keep inputs on which solver and reference
+                             implementation disagree
+    --timeout <secs>         keep inputs that take longer to solve or hang
+    -o, --output <path>      write the reproducer to a file instead of stdout";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Command {
+    Run,
+    Test,
+    Record,
+    Bench,
+    Diff,
+    Generate,
+    Minimize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Table,
+    Plain,
+    Json,
+}
+
+struct Options {
+    command: Command,
+    days: Vec<u8>,
+    part: Option<Part>,
+    input: Source,
+    format: Format,
+    answers: Option<PathBuf>,
+    hashed: bool,
+    bench: bench::Config,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    seed: Option<u64>,
+    cases: usize,
+    size: usize,
+    output: Option<PathBuf>,
+    predicate: Predicate,
+}
+
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+) -> Result<Options, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("test") => Command::Test,
+        Some("record") => Command::Record,
+        Some("bench") => Command::Bench,
+        Some("diff") => Command::Diff,
+        Some("generate") => Command::Generate,
+        Some("minimize") => Command::Minimize,
+        Some(other) => return Err(format!("unknown command `{other}`")),
+        None => return Err("missing command".to_owned()),
+    };
+
+    let mut options = Options {
+        command,
+        days: vec![],
+        part: None,
+        input: Source::default_dir(),
+        format: Format::Table,
+        answers: None,
+        hashed: false,
+        bench: bench::Config::default(),
+        save: None,
+        baseline: None,
+        threshold: 0.1,
+        seed: None,
+        cases: 100,
+        size: 8,
+        output: None,
+        predicate: Predicate::Panic,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+
+        match arg.as_str() {
+            "-p" | "--part" => {
+                options.part = match value()?.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    other => return Err(format!("invalid part `{other}`")),
+                }
+            }
+            "-i" | "--input" => options.input = Source::from_arg(&value()?),
+            "-d" | "--input-dir" => {
+                options.input = Source::Dir(value()?.into())
+            }
+            "-f" | "--format" => {
+                options.format = match value()?.as_str() {
+                    "table" => Format::Table,
+                    "plain" => Format::Plain,
+                    "json" => Format::Json,
+                    other => return Err(format!("invalid format `{other}`")),
+                }
+            }
+            "-a" | "--answers" => options.answers = Some(value()?.into()),
+            "--hashed" => options.hashed = true,
+            "-n" | "--samples" => {
+                options.bench.samples = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("samples must be a positive number")?
+            }
+            "-w" | "--warmup" => {
+                options.bench.warmup =
+                    value()?.parse().map_err(|_| "warmup must be a number")?
+            }
+            "-b" | "--budget" => {
+                options.bench.budget = value()?
+                    .parse()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .ok_or("budget must be a number of seconds")?
+            }
+            "--save" => options.save = Some(value()?.into()),
+            "--baseline" => options.baseline = Some(value()?.into()),
+            "--threshold" => {
+                options.threshold = value()?
+                    .parse::<f64>()
+                    .map_err(|_| "threshold must be a percentage")?
+                    / 100.0
+            }
+            "--seed" => {
+                options.seed =
+                    Some(value()?.parse().map_err(|_| "invalid seed")?)
+            }
+            "--cases" => {
+                options.cases =
+                    value()?.parse().map_err(|_| "cases must be a number")?
+            }
+            "--size" => {
+                options.size =
+                    value()?.parse().map_err(|_| "size must be a number")?
+            }
+            "-o" | "--output" => options.output = Some(value()?.into()),
+            "--panic" => options.predicate = Predicate::Panic,
+            "--mismatch" => options.predicate = Predicate::Mismatch,
+            "--timeout" => {
+                options.predicate = value()?
+                    .parse()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .map(Predicate::Timeout)
+                    .ok_or("timeout must be a number of seconds")?
+            }
+            day => match day.parse() {
+                Ok(day) if runner::day(day).is_some() => options.days.push(day),
+                _ => return Err(format!("invalid day `{day}`")),
+            },
+        }
+    }
+
+    if matches!(options.command, Command::Generate | Command::Minimize)
+        && options.days.len() != 1
+    {
+        return Err("this command requires exactly one day".to_owned());
+    }
+
+    if options.days.is_empty() {
+        options.days = DAYS.iter().map(|d| d.number).collect();
+    }
+
+    if !matches!(options.input, Source::Dir(_)) && options.days.len() != 1 {
+        return Err("`--input` requires exactly one day".to_owned());
+    }
+
+    Ok(options)
+}
+
+fn read_input(options: &Options, day: &Day) -> Option<String> {
+    options
+        .input
+        .read(day.number)
+        .inspect_err(|e| eprintln!("error: {e}"))
+        .ok()
+}
+
+/// Solves `day` and reports parse errors and parts that failed. A day that
+/// parsed is returned even if some of its parts failed, so the others are
+/// still shown.
+fn solve(options: &Options, day: &Day, input: &str) -> Option<Run> {
+    let run = day
+        .solve(input, options.part)
+        .inspect_err(|e| {
+            eprintln!("error: day {:02}: {}", day.number, e.snippet())
+        })
+        .ok()?;
+
+    for e in &run.errors {
+        eprintln!(
+            "error: day {:02} part{}: {}",
+            day.number,
+            e.part.number(),
+            e.message
+        );
+    }
+
+    Some(run)
+}
+
+fn fmt_time(time: Duration) -> String {
+    format!("{time:.2?}")
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    if rows.is_empty() {
+        return;
+    }
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    line(header.to_vec());
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        line(row.iter().map(|s| s.as_str()).collect());
+    }
+}
+
+fn table_row(day: u8, run: &Run) -> Vec<String> {
+    let mut row = vec![format!("{day:02}"), fmt_time(run.parse)];
+
+    for part in [Part::One, Part::Two] {
+        match run.part(part) {
+            Some(p) => {
+                row.push(p.answer.clone());
+                row.push(fmt_time(p.time));
+            }
+            None => {
+                row.push("-".to_owned());
+                row.push("-".to_owned());
+            }
+        }
+    }
+
+    row
+}
+
+const TABLE_HEADER: [&str; 6] =
+    ["day", "parse", "part 1", "time", "part 2", "time"];
+
+fn run(options: &Options) -> bool {
+    let mut ok = true;
+    let mut rows = vec![];
+    let mut reports = vec![];
+
+    for &number in &options.days {
+        let day = runner::day(number).unwrap();
+
+        let Some(input) = read_input(options, day) else {
+            ok = false;
+            continue;
+        };
+
+        let Some(run) = solve(options, day, &input) else {
+            ok = false;
+            continue;
+        };
+        ok &= run.errors.is_empty();
+
+        match options.format {
+            Format::Table => rows.push(table_row(number, &run)),
+            Format::Json => reports.extend(run.reports(number, &input)),
+            Format::Plain => {
+                for p in &run.parts {
+                    println!(
+                        "day{number:02} part{} = {}",
+                        p.part.number(),
+                        p.answer
+                    );
+                }
+            }
+        }
+    }
+
+    match options.format {
+        Format::Table => print_table(&TABLE_HEADER, &rows),
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(&reports).unwrap())
+        }
+        Format::Plain => {}
+    }
+
+    ok
+}
+
+fn answers_path(options: &Options) -> PathBuf {
+    match &options.answers {
+        Some(path) => path.clone(),
+        None => match &options.input {
+            Source::Dir(dir) => dir.join("answers.txt"),
+            _ => input::dir().join("answers.txt"),
+        },
+    }
+}
+
+fn test(options: &Options) -> bool {
+    let path = answers_path(options);
+
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: cannot load {}: {e}", path.display());
+            return false;
+        }
+    };
+
+    let mut ok = true;
+
+    for &number in &options.days {
+        let day = runner::day(number).unwrap();
+
+        let Some(run) = read_input(options, day)
+            .and_then(|input| solve(options, day, &input))
+        else {
+            ok = false;
+            continue;
+        };
+        ok &= run.errors.is_empty();
+
+        for p in run.parts {
+            let status = match answers.get(number, p.part) {
+                Some(expected)
+                    if expected.matches(number, p.part, &p.answer) =>
+                {
+                    "ok".to_owned()
+                }
+                Some(expected) => {
+                    ok = false;
+                    format!("FAILED: expected {expected}, got {}", p.answer)
+                }
+                None => format!("no expected answer, got {}", p.answer),
+            };
+
+            println!("day{number:02} part{} ... {status}", p.part.number());
+        }
+    }
+
+    ok
+}
+
+fn record(options: &Options) -> bool {
+    let path = answers_path(options);
+
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+        Err(e) => {
+            eprintln!("error: cannot load {}: {e}", path.display());
+            return false;
+        }
+    };
+
+    let mut ok = true;
+
+    for &number in &options.days {
+        let day = runner::day(number).unwrap();
+
+        let Some(run) = read_input(options, day)
+            .and_then(|input| solve(options, day, &input))
+        else {
+            ok = false;
+            continue;
+        };
+        ok &= run.errors.is_empty();
+
+        for p in run.parts {
+            answers.record(number, p.part, &p.answer, options.hashed);
+            println!("day{number:02} part{} = {}", p.part.number(), p.answer);
+        }
+    }
+
+    if let Err(e) = answers.save(&path) {
+        eprintln!("error: cannot save {}: {e}", path.display());
+        ok = false;
+    }
+
+    ok
+}
+
+fn bench(options: &Options) -> bool {
+    let baseline = match &options.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("error: cannot load {}: {e}", path.display());
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let mut ok = true;
+    let mut rows = vec![];
+    let mut current = Baseline::default();
+    let mut regressions = 0;
+
+    for &number in &options.days {
+        let day = runner::day(number).unwrap();
+
+        let Some(input) = read_input(options, day) else {
+            ok = false;
+            continue;
+        };
+
+        let phases = match day.bench(&input, options.part, &options.bench) {
+            Ok(phases) => phases,
+            Err(e) => {
+                eprintln!("error: day {number:02}: {}", e.snippet());
+                ok = false;
+                continue;
+            }
+        };
+
+        for (phase, stats) in phases {
+            let change =
+                match baseline.as_ref().and_then(|b| b.get(number, phase)) {
+                    Some(base) => {
+                        let change = bench::change(base, &stats);
+                        if bench::is_regression(base, &stats, options.threshold)
+                        {
+                            regressions += 1;
+                            format!("{:+.1}% REGRESSION", change * 100.0)
+                        } else {
+                            format!("{:+.1}%", change * 100.0)
+                        }
+                    }
+                    None => "-".to_owned(),
+                };
+
+            rows.push(vec![
+                format!("{number:02}"),
+                phase.to_string(),
+                fmt_time(stats.mean),
+                fmt_time(stats.median),
+                fmt_time(stats.stddev),
+                fmt_time(stats.min),
+                stats.samples.to_string(),
+                change,
+            ]);
+
+            current.insert(number, phase, stats);
+        }
+    }
+
+    print_table(
+        &[
+            "day", "phase", "mean", "median", "stddev", "min", "samples",
+            "change",
+        ],
+        &rows,
+    );
+
+    if let Some(path) = &options.save
+        && let Err(e) = current.save(path)
+    {
+        eprintln!("error: cannot save {}: {e}", path.display());
+        ok = false;
+    }
+
+    if regressions > 0 {
+        eprintln!("{regressions} regression(s) against the baseline");
+        ok = false;
+    }
+
+    ok
+}
+
+fn seed(options: &Options) -> u64 {
+    options.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    })
+}
+
+fn diff(options: &Options) -> bool {
+    let seed = seed(options);
+
+    let mut ok = true;
+    let mut checked = 0;
+
+    for &number in &options.days {
+        let Some(check) = differential::check(number) else {
+            continue;
+        };
+        checked += 1;
+
+        match differential::run(check, seed, options.cases, options.size) {
+            Ok(()) => {
+                println!("day{number:02} ... ok ({} cases)", options.cases)
+            }
+            Err(failure) => {
+                ok = false;
+                println!(
+                    "day{number:02} ... FAILED (seed {}, size {})",
+                    failure.seed, failure.size
+                );
+
+                for m in &failure.mismatches {
+                    println!(
+                        "    part{}: reference {}, solver {}",
+                        m.part.number(),
+                        m.expected,
+                        m.actual.as_deref().unwrap_or("panicked")
+                    );
+                }
+
+                println!("smallest failing input:\n{}", failure.input);
+            }
+        }
+    }
+
+    if checked == 0 {
+        eprintln!("no reference implementation for the selected days");
+        return false;
+    }
+
+    println!("seed {seed}");
+
+    ok
+}
+
+fn generate(options: &Options) -> bool {
+    let number = options.days[0];
+    let Some(generate) = generator::generator(number) else {
+        eprintln!("error: no generator for day {number:02}");
+        return false;
+    };
+
+    let seed = seed(options);
+    let input = generate(&mut Rng::new(seed), options.size);
+
+    if !write_output(options, &input) {
+        return false;
+    }
+
+    eprintln!("seed {seed}, size {}", options.size);
+
+    true
+}
+
+fn minimize(options: &Options) -> bool {
+    let day = runner::day(options.days[0]).unwrap();
+
+    if options.predicate == Predicate::Mismatch
+        && differential::check(day.number).is_none()
+    {
+        eprintln!(
+            "error: no reference implementation for day {:02}",
+            day.number
+        );
+        return false;
+    }
+
+    let Some(input) = read_input(options, day) else {
+        return false;
+    };
+
+    // every panicking attempt would print its message otherwise
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut runs = 0;
+    let mut fails = |input: &str| {
+        runs += 1;
+        options.predicate.holds(day, options.part, input)
+    };
+
+    if !fails(&input) {
+        eprintln!("error: the input doesn't reproduce the failure");
+        return false;
+    }
+
+    let reproducer = minimize::minimize(&input, fails);
+
+    if !write_output(options, &reproducer) {
+        return false;
+    }
+
+    eprintln!(
+        "shrunk {} to {} lines in {runs} runs",
+        input.lines().count(),
+        reproducer.lines().count()
+    );
+
+    true
+}
+
+/// Writes `text` to the output file, or to stdout if there is none.
+fn write_output(options: &Options, text: &str) -> bool {
+    match &options.output {
+        Some(path) => fs::write(path, text)
+            .inspect_err(|e| {
+                eprintln!("error: cannot write {}: {e}", path.display())
+            })
+            .is_ok(),
+        None => {
+            print!("{text}");
+            true
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let ok = match options.command {
+        Command::Run => run(&options),
+        Command::Test => test(&options),
+        Command::Record => record(&options),
+        Command::Bench => bench(&options),
+        Command::Diff => diff(&options),
+        Command::Generate => generate(&options),
+        Command::Minimize => minimize(&options),
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,377 @@
+use crate::Solution;
+use crate::differential::Reference;
+use crate::parse::{self, ParseError};
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if let Some(num) = s.strip_prefix("L") {
+                Ok(-parse::number::<i64>(input, num, "number")?)
+            } else if let Some(num) = s.strip_prefix("R") {
+                parse::number(input, num, "number")
+            } else {
+                Err(ParseError::at(input, s, "`L` or `R`"))
+            }
+        })
+        .collect()
+}
+
+/// Dial with `size` positions numbered from 0, counting how often a set of
+/// target positions is reached. Positions outside `0..size`, including
+/// negative ones, wrap around.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    start: i64,
+    targets: Vec<i64>,
+}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, targets: &[i64]) -> Self {
+        assert!(size > 0, "dial needs at least one position");
+
+        let mut targets: Vec<i64> =
+            targets.iter().map(|t| t.rem_euclid(size)).collect();
+        targets.sort();
+        targets.dedup();
+
+        Self {
+            size,
+            start: start.rem_euclid(size),
+            targets,
+        }
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn targets(&self) -> &[i64] {
+        &self.targets
+    }
+
+    pub fn is_target(&self, pos: i64) -> bool {
+        self.targets
+            .binary_search(&pos.rem_euclid(self.size))
+            .is_ok()
+    }
+
+    /// Position after rotating `rotation` clicks away from `pos`, negative
+    /// rotations turn left.
+    pub fn turn(&self, pos: i64, rotation: i64) -> i64 {
+        // the sum may not fit into 64 bits, the position always does
+        (pos as i128 + rotation as i128).rem_euclid(self.size as i128) as i64
+    }
+
+    /// Clicks landing on a target while rotating `rotation` clicks away from
+    /// `pos`, which has to be in `0..size`.
+    pub fn hits(&self, pos: i64, rotation: i64) -> i64 {
+        let (pos, rotation, size) =
+            (pos as i128, rotation as i128, self.size as i128);
+
+        // clicks reach the positions (pos, pos + rotation] when turning
+        // right and [pos + rotation, pos) when turning left
+        let (low, high) = if rotation >= 0 {
+            (pos, pos + rotation)
+        } else {
+            (pos + rotation - 1, pos - 1)
+        };
+
+        let hits: i128 = self
+            .targets
+            .iter()
+            .map(|&t| {
+                (high - t as i128).div_euclid(size)
+                    - (low - t as i128).div_euclid(size)
+            })
+            .sum();
+
+        // at most one hit per click
+        hits as i64
+    }
+
+    /// Replays `rotations` from the start position, one step per rotation.
+    pub fn trace<'a>(
+        &'a self,
+        rotations: &'a [i64],
+    ) -> impl Iterator<Item = Step> + 'a {
+        rotations.iter().enumerate().scan(
+            self.start,
+            move |pos, (index, &rotation)| {
+                let before = *pos;
+                *pos = self.turn(before, rotation);
+
+                Some(Step {
+                    index,
+                    rotation,
+                    before,
+                    after: *pos,
+                    hits: self.hits(before, rotation),
+                })
+            },
+        )
+    }
+
+    /// Step of the rotation at `index`, `None` if there are fewer rotations.
+    pub fn step_at(&self, rotations: &[i64], index: usize) -> Option<Step> {
+        self.trace(rotations).nth(index)
+    }
+
+    /// Rotations moving the dial from the start position to each of `stops`
+    /// in turn. Every rotation is shorter than a full turn, the direction is
+    /// chosen according to `goal`.
+    pub fn rotations_to(&self, stops: &[i64], goal: Goal) -> Vec<i64> {
+        let mut pos = self.start;
+        let mut rotations = vec![];
+
+        for stop in stops {
+            let right = (stop - pos).rem_euclid(self.size);
+            let candidates = if right == 0 {
+                vec![0]
+            } else {
+                vec![right, right - self.size]
+            };
+
+            // positions are fixed by the stops, so the best rotation of each
+            // step is part of the best list
+            let clicks = |r: &i64| r.abs();
+            let hits = |r: &i64| self.hits(pos, *r);
+            let best = match goal {
+                Goal::Shortest => candidates.into_iter().min_by_key(clicks),
+                Goal::MostHits => {
+                    candidates.into_iter().min_by_key(|r| (-hits(r), clicks(r)))
+                }
+                Goal::FewestHits => {
+                    candidates.into_iter().min_by_key(|r| (hits(r), clicks(r)))
+                }
+            };
+
+            let rotation = best.unwrap();
+            rotations.push(rotation);
+            pos = self.turn(pos, rotation);
+        }
+
+        rotations
+    }
+
+    /// Number of rotations ending on a target.
+    pub fn ends_on_target(&self, rotations: &[i64]) -> i64 {
+        self.trace(rotations)
+            .filter(|step| self.is_target(step.after))
+            .count() as i64
+    }
+
+    /// Number of clicks landing on a target, including those ending a
+    /// rotation. Huge rotations can add up to more than 64 bits.
+    pub fn passes_through_target(&self, rotations: &[i64]) -> i128 {
+        self.trace(rotations).map(|step| step.hits as i128).sum()
+    }
+}
+
+/// A single rotation of a trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Index of the rotation in the input, starting at 0.
+    pub index: usize,
+    pub rotation: i64,
+    pub before: i64,
+    pub after: i64,
+    /// Clicks landing on a target during the rotation.
+    pub hits: i64,
+}
+
+impl Step {
+    /// The rotation in the input format, e.g. `L68`.
+    pub fn instruction(&self) -> String {
+        instruction(self.rotation)
+    }
+}
+
+/// What the inverse solver optimizes besides reaching every stop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Goal {
+    /// Fewest clicks.
+    Shortest,
+    /// Most target hits, then fewest clicks.
+    MostHits,
+    /// Fewest target hits, then fewest clicks.
+    FewestHits,
+}
+
+fn instruction(rotation: i64) -> String {
+    if rotation < 0 {
+        format!("L{}", -rotation)
+    } else {
+        format!("R{rotation}")
+    }
+}
+
+/// Renders `rotations` in the input format, the inverse of `parse`.
+pub fn format(rotations: &[i64]) -> String {
+    rotations.iter().map(|&r| instruction(r) + "\n").collect()
+}
+
+/// Renders `steps` as CSV with a header line.
+pub fn trace_csv(steps: impl IntoIterator<Item = Step>) -> String {
+    let mut csv = "index,instruction,before,after,hits\n".to_owned();
+
+    for step in steps {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            step.index,
+            step.instruction(),
+            step.before,
+            step.after,
+            step.hits
+        ));
+    }
+
+    csv
+}
+
+impl Default for Dial {
+    /// The dial of the puzzle: 100 positions, starting at 50, counting 0.
+    fn default() -> Self {
+        Self::new(100, 50, &[0])
+    }
+}
+
+pub fn part1(puzzle: &[i64]) -> i64 {
+    Dial::default().ends_on_target(puzzle)
+}
+
+pub fn part2(puzzle: &[i64]) -> i128 {
+    Dial::default().passes_through_target(puzzle)
+}
+
+// turns the dial one click at a time, too slow for huge rotations
+fn reference_part2(puzzle: &[i64]) -> Option<i128> {
+    if puzzle
+        .iter()
+        .map(|dir| dir.unsigned_abs() as u128)
+        .sum::<u128>()
+        > 10_000_000
+    {
+        return None;
+    }
+
+    let mut acc = 50;
+    let mut count = 0;
+
+    for dir in puzzle {
+        let steps = dir.abs();
+        let sign = if *dir > 0 { 1 } else { -1 };
+
+        for _ in 0..steps {
+            acc += sign;
+            acc %= 100;
+
+            if acc == 0 {
+                count += 1;
+            }
+        }
+    }
+
+    Some(count)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+impl Reference for Day01 {
+    fn reference1(_: &Self::Input) -> Option<Self::Answer1> {
+        None
+    }
+
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2> {
+        reference_part2(input)
+    }
+}
+
+#[test]
+fn test_day01() {
+    let input = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 3);
+    assert_eq!(part2(&input), 6);
+    assert_eq!(part2(&[1_000_000_000]), 10_000_000);
+    assert_eq!(part2(&[-50, -1_000_000_000, 1]), 10_000_001);
+    assert_eq!(part2(&[i64::MAX - 10]), 92_233_720_368_547_758);
+    assert_eq!(part2(&[i64::MAX; 200]), 18_446_744_073_709_551_614);
+    assert_eq!(
+        part2(&[-i64::MAX, i64::MAX, -i64::MAX]),
+        276_701_161_105_643_274
+    );
+    assert_eq!(reference_part2(&[i64::MAX, -i64::MAX]), None);
+    for puzzle in [&[-50, 100, -100][..], &[-250, 49, -99], &[150, -1, 1]] {
+        assert_eq!(Some(part2(puzzle)), reference_part2(puzzle));
+    }
+
+    let trace = trace_csv(Dial::default().trace(&input).take(2));
+    assert_eq!(
+        trace,
+        "index,instruction,before,after,hits\n0,L68,50,82,1\n1,L30,82,52,0\n"
+    );
+    let step = Dial::default().step_at(&input, 2).unwrap();
+    assert_eq!(
+        (step.instruction(), step.after, step.hits),
+        ("R48".into(), 0, 1)
+    );
+    assert_eq!(Dial::default().step_at(&input, 10), None);
+
+    let stops: Vec<i64> =
+        Dial::default().trace(&input).map(|s| s.after).collect();
+    let shortest = Dial::default().rotations_to(&stops, Goal::Shortest);
+    let rotations = parse(&format(&shortest)).unwrap();
+    assert_eq!(rotations, shortest);
+    assert_eq!(rotations, [32, -30, 48, -5, -40, 45, -1, 1, 14, 18]);
+    assert_eq!(part1(&rotations), 3);
+    let most = Dial::default().rotations_to(&stops, Goal::MostHits);
+    let fewest = Dial::default().rotations_to(&stops, Goal::FewestHits);
+    assert_eq!((part2(&fewest), part2(&shortest), part2(&most)), (3, 3, 7));
+
+    let dial = Dial::new(10, -3, &[0, -5, 10]);
+    assert_eq!((dial.start(), dial.targets()), (7, &[0, 5][..]));
+    assert_eq!(dial.ends_on_target(&[5, -13, 6]), 1);
+    assert_eq!(dial.passes_through_target(&[5, -13, 6]), 6);
+    assert_eq!(dial.passes_through_target(&[-7, -10]), 4);
+
+    let err = parse("L68\nR3x\nL5").unwrap_err();
+    assert_eq!((err.line, err.column, err.expected), (2, 2, "number"));
+}
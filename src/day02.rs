@@ -0,0 +1,599 @@
+use std::fmt;
+
+use crate::differential::Reference;
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<(u128, u128)>, ParseError> {
+    parse_radix(input, 10)
+}
+
+/// Parses ranges whose endpoints are written in `radix`, which has to be in
+/// `2..=36`.
+pub fn parse_radix(
+    input: &str,
+    radix: u32,
+) -> Result<Vec<(u128, u128)>, ParseError> {
+    let number = |token: &str| {
+        u128::from_str_radix(token, radix)
+            .map_err(|_| ParseError::at(input, token, "number"))
+    };
+
+    input
+        .split(',')
+        .map(|r| r.trim())
+        .filter(|r| !r.is_empty())
+        .map(|r| {
+            let (begin, end) = r
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, &r[r.len()..], "`-`"))?;
+
+            Ok((number(begin)?, number(end)?))
+        })
+        .collect()
+}
+
+/// Sum that doesn't fit into 128 bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sum exceeds 128 bits")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Answer of both parts, an overflowing sum is reported as error instead of
+/// wrapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Total(pub Result<u128, Overflow>);
+
+impl Answer for Total {
+    fn render(&self) -> Result<Option<String>, String> {
+        match self.0 {
+            Ok(sum) => Ok(Some(sum.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+fn digits(num: u128, radix: u128) -> u32 {
+    num.checked_ilog(radix).map_or(1, |d| d + 1)
+}
+
+/// Multiplier repeating a block of `size` digits `count` times, e.g. 10101
+/// for two decimal digits repeated three times. `None` if it doesn't fit, so
+/// no such ID does either.
+fn repeater(size: u32, count: u32, radix: u128) -> Option<u128> {
+    (0..count).try_fold(0_u128, |acc, i| {
+        acc.checked_add(radix.checked_pow(size * i)?)
+    })
+}
+
+/// Blocks of `size` digits which, repeated `count` times, produce an ID in
+/// `begin..=end`, and the multiplier doing so.
+fn blocks(
+    begin: u128,
+    end: u128,
+    size: u32,
+    count: u32,
+    radix: u128,
+) -> Option<(u128, u128, u128)> {
+    let repeater = repeater(size, count, radix)?;
+    let first = radix.pow(size - 1).max(begin.div_ceil(repeater));
+    let last = (radix.pow(size) - 1).min(end / repeater);
+
+    (first <= last).then_some((first, last, repeater))
+}
+
+/// IDs in `begin..=end` made of a block of `size` digits repeated `count`
+/// times. Only blocks producing an ID inside the range are tried.
+fn repetitions(
+    begin: u128,
+    end: u128,
+    size: u32,
+    count: u32,
+    radix: u128,
+) -> impl Iterator<Item = (u128, u128)> {
+    blocks(begin, end, size, count, radix).into_iter().flat_map(
+        |(first, last, repeater)| {
+            (first..=last).map(move |block| (block, block * repeater))
+        },
+    )
+}
+
+/// Number and sum of IDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Tally {
+    count: u128,
+    sum: u128,
+}
+
+impl Tally {
+    fn add(self, other: Tally) -> Result<Tally, Overflow> {
+        Ok(Tally {
+            count: self.count.checked_add(other.count).ok_or(Overflow)?,
+            sum: self.sum.checked_add(other.sum).ok_or(Overflow)?,
+        })
+    }
+
+    /// Removes `other`, which has to be contained in `self`.
+    fn sub(self, other: Tally) -> Tally {
+        Tally {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+/// Tally of the IDs in `begin..=end` made of a block of `size` digits
+/// repeated `count` times, without enumerating them.
+fn tally(
+    begin: u128,
+    end: u128,
+    size: u32,
+    count: u32,
+    radix: u128,
+) -> Result<Tally, Overflow> {
+    let Some((first, last, repeater)) = blocks(begin, end, size, count, radix)
+    else {
+        return Ok(Tally::default());
+    };
+
+    // first + last is even whenever the number of blocks is odd
+    let n = last - first + 1;
+    let blocks = if n.is_multiple_of(2) {
+        (n / 2).checked_mul(first + last)
+    } else {
+        n.checked_mul((first + last) / 2)
+    };
+
+    Ok(Tally {
+        count: n,
+        sum: blocks
+            .and_then(|b| b.checked_mul(repeater))
+            .ok_or(Overflow)?,
+    })
+}
+
+/// Tally of the IDs in `begin..=end` made of a block repeated exactly twice.
+fn tally_twice(begin: u128, end: u128, radix: u128) -> Result<Tally, Overflow> {
+    (digits(begin, radix)..=digits(end, radix))
+        .filter(|len| len.is_multiple_of(2))
+        .try_fold(Tally::default(), |acc, len| {
+            acc.add(tally(begin, end, len / 2, 2, radix)?)
+        })
+}
+
+/// Tally of the IDs in `begin..=end` made of a block repeated at least twice.
+/// An ID of `len` digits repeats a block of `len / p` digits for some prime
+/// `p`, overlaps between the primes are removed by inclusion-exclusion.
+fn tally_repeated(
+    begin: u128,
+    end: u128,
+    radix: u128,
+) -> Result<Tally, Overflow> {
+    let mut total = Tally::default();
+
+    for len in digits(begin, radix)..=digits(end, radix) {
+        let primes: Vec<u32> = (2..=len)
+            .filter(|p| len.is_multiple_of(*p) && (2..*p).all(|d| p % d != 0))
+            .collect();
+
+        let mut added = Tally::default();
+        let mut removed = Tally::default();
+
+        for subset in 1..1_u32 << primes.len() {
+            let product: u32 = (0..primes.len())
+                .filter(|i| subset & (1 << i) != 0)
+                .map(|i| primes[i])
+                .product();
+            let tally = tally(begin, end, len / product, product, radix)?;
+
+            if subset.count_ones() % 2 == 1 {
+                added = added.add(tally)?;
+            } else {
+                removed = removed.add(tally)?;
+            }
+        }
+
+        total = total.add(added.sub(removed))?;
+    }
+
+    Ok(total)
+}
+
+/// Whether `block` of `size` digits is itself a smaller block repeated.
+fn is_periodic(block: u128, size: u32, radix: u128) -> bool {
+    (1..size).filter(|s| size.is_multiple_of(*s)).any(|s| {
+        repeater(s, size / s, radix)
+            .is_some_and(|r| block / radix.pow(size - s) * r == block)
+    })
+}
+
+/// Every ID of `len` digits in `begin..=end` made of a block of digits
+/// repeated at least twice, with the block length and repetition count. IDs
+/// repeating in several ways, like `111111`, appear once with their shortest
+/// block.
+fn repeated_with_len(
+    begin: u128,
+    end: u128,
+    len: u32,
+    radix: u128,
+) -> impl Iterator<Item = (u128, u32, u32)> {
+    (1..len)
+        .filter(move |size| len.is_multiple_of(*size))
+        .flat_map(move |size| {
+            let count = len / size;
+
+            // a periodic block means the ID has a shorter one
+            repetitions(begin, end, size, count, radix)
+                .filter(move |&(block, _)| !is_periodic(block, size, radix))
+                .map(move |(_, id)| (id, size, count))
+        })
+}
+
+/// An ID made of a block of digits repeated at least twice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub id: u128,
+    /// Length of the shortest repeated block in digits.
+    pub block: u32,
+    pub repetitions: u32,
+}
+
+impl Match {
+    /// Whether the ID is also a block repeated exactly twice, as counted by
+    /// part 1.
+    pub fn is_twice(&self) -> bool {
+        self.repetitions.is_multiple_of(2)
+    }
+}
+
+/// Matched IDs in `begin..=end` in ascending order, digits are counted in
+/// `radix`.
+pub fn matches(
+    begin: u128,
+    end: u128,
+    radix: u32,
+) -> impl Iterator<Item = Match> {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+    let radix = radix as u128;
+
+    // IDs of one length are few enough to be sorted
+    (digits(begin, radix)..=digits(end, radix)).flat_map(move |len| {
+        let mut matches: Vec<Match> = repeated_with_len(begin, end, len, radix)
+            .map(|(id, block, repetitions)| Match {
+                id,
+                block,
+                repetitions,
+            })
+            .collect();
+        matches.sort_by_key(|m| m.id);
+        matches
+    })
+}
+
+/// Matches of a single range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub begin: u128,
+    pub end: u128,
+    pub count: u128,
+    /// Sum of the IDs repeated twice.
+    pub sum_twice: u128,
+    /// Sum of all matched IDs.
+    pub sum_repeated: u128,
+}
+
+pub fn summaries(
+    puzzle: &[(u128, u128)],
+    radix: u32,
+) -> Result<Vec<Summary>, Overflow> {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+    let radix = radix as u128;
+
+    puzzle
+        .iter()
+        .map(|&(begin, end)| {
+            let repeated = tally_repeated(begin, end, radix)?;
+
+            Ok(Summary {
+                begin,
+                end,
+                count: repeated.count,
+                sum_twice: tally_twice(begin, end, radix)?.sum,
+                sum_repeated: repeated.sum,
+            })
+        })
+        .collect()
+}
+
+/// Every matched ID with its range as CSV, numbers are written in decimal.
+pub fn matches_csv(puzzle: &[(u128, u128)], radix: u32) -> String {
+    let mut csv = "begin,end,id,block,repetitions,twice\n".to_owned();
+
+    for &(begin, end) in puzzle {
+        for m in matches(begin, end, radix) {
+            csv.push_str(&format!(
+                "{begin},{end},{},{},{},{}\n",
+                m.id,
+                m.block,
+                m.repetitions,
+                m.is_twice()
+            ));
+        }
+    }
+
+    csv
+}
+
+/// The summaries of all ranges as CSV, numbers are written in decimal.
+pub fn summaries_csv(
+    puzzle: &[(u128, u128)],
+    radix: u32,
+) -> Result<String, Overflow> {
+    let mut csv = "begin,end,count,sum_twice,sum_repeated\n".to_owned();
+
+    for s in summaries(puzzle, radix)? {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            s.begin, s.end, s.count, s.sum_twice, s.sum_repeated
+        ));
+    }
+
+    Ok(csv)
+}
+
+/// Sum of the IDs made of a block of digits in `radix` repeated twice.
+pub fn sum_twice(
+    puzzle: &[(u128, u128)],
+    radix: u32,
+) -> Result<u128, Overflow> {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+    puzzle.iter().try_fold(0_u128, |sum, &(begin, end)| {
+        let tally = tally_twice(begin, end, radix as u128)?;
+        sum.checked_add(tally.sum).ok_or(Overflow)
+    })
+}
+
+/// Sum of the IDs made of a block of digits in `radix` repeated at least
+/// twice.
+pub fn sum_repeated(
+    puzzle: &[(u128, u128)],
+    radix: u32,
+) -> Result<u128, Overflow> {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+    puzzle.iter().try_fold(0_u128, |sum, &(begin, end)| {
+        let tally = tally_repeated(begin, end, radix as u128)?;
+        sum.checked_add(tally.sum).ok_or(Overflow)
+    })
+}
+
+pub fn part1(puzzle: &[(u128, u128)]) -> Result<u128, Overflow> {
+    sum_twice(puzzle, 10)
+}
+
+pub fn part2(puzzle: &[(u128, u128)]) -> Result<u128, Overflow> {
+    sum_repeated(puzzle, 10)
+}
+
+fn too_wide(puzzle: &[(u128, u128)]) -> bool {
+    puzzle
+        .iter()
+        .map(|(begin, end)| end.saturating_sub(*begin))
+        .fold(0_u128, u128::saturating_add)
+        > 10_000_000
+}
+
+fn is_invalid1(num: u128, radix: u128) -> bool {
+    let digits = digits(num, radix);
+    let half_digits = digits / 2;
+
+    let first = num % radix.pow(half_digits);
+    let second = num / radix.pow(half_digits);
+
+    digits.is_multiple_of(2) && first == second
+}
+
+// tests every number of every range
+fn reference_part1(puzzle: &[(u128, u128)], radix: u128) -> Option<u128> {
+    if too_wide(puzzle) {
+        return None;
+    }
+
+    let mut sum = 0;
+    for &(begin, end) in puzzle {
+        for n in begin..=end {
+            if is_invalid1(n, radix) {
+                sum += n;
+            }
+        }
+    }
+
+    Some(sum)
+}
+
+fn is_invalid2(num: u128, radix: u128) -> bool {
+    let digits = digits(num, radix);
+
+    for size in 1..=digits / 2 {
+        let mut rest = num;
+        let chunk = radix.pow(size);
+
+        let mut prev = None;
+        let mut invalid = true;
+
+        if !digits.is_multiple_of(size) {
+            continue;
+        }
+
+        while rest > 0 {
+            if let Some(prev) = prev
+                && rest % chunk != prev
+            {
+                invalid = false;
+                break;
+            }
+
+            prev = Some(rest % chunk);
+
+            rest /= chunk;
+        }
+
+        if invalid {
+            return invalid;
+        }
+    }
+
+    false
+}
+
+// tests every number of every range
+fn reference_part2(puzzle: &[(u128, u128)], radix: u128) -> Option<u128> {
+    if too_wide(puzzle) {
+        return None;
+    }
+
+    let mut sum = 0;
+    for &(begin, end) in puzzle {
+        for n in begin..=end {
+            if is_invalid2(n, radix) {
+                sum += n;
+            }
+        }
+    }
+
+    Some(sum)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u128, u128)>;
+    type Answer1 = Total;
+    type Answer2 = Total;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Total(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Total(part2(input))
+    }
+}
+
+impl Reference for Day02 {
+    fn reference1(input: &Self::Input) -> Option<Self::Answer1> {
+        reference_part1(input, 10).map(|sum| Total(Ok(sum)))
+    }
+
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2> {
+        reference_part2(input, 10).map(|sum| Total(Ok(sum)))
+    }
+}
+
+#[test]
+fn test_day02() {
+    let input = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), Ok(1227775554));
+    assert_eq!(part2(&input), Ok(4174379265));
+    assert_eq!(reference_part2(&input, 10), Some(4174379265));
+
+    let ids: Vec<_> = matches(1, 1_000_000_000, 10).collect();
+    assert_eq!(ids.len(), 11007);
+    assert!(ids.contains(&Match {
+        id: 111111,
+        block: 1,
+        repetitions: 6
+    }));
+    assert!(ids.contains(&Match {
+        id: 123123123,
+        block: 3,
+        repetitions: 3
+    }));
+    assert_eq!(part1(&[(1, 9_999_999_999)]), Ok(495495949990950));
+    let summary = summaries(&[(1, 1_000_000_000)], 10).unwrap();
+    assert_eq!(summary[0].count, 11007);
+
+    let hex = parse_radix("1-fff,aa-100,abab-abac", 16).unwrap();
+    assert_eq!(hex, [(1, 0xfff), (0xaa, 0x100), (0xabab, 0xabac)]);
+    for radix in [2, 3, 10, 16, 36] {
+        let ranges = [(0, 5000), (70000, 90000), (999_990, 1_000_010)];
+        let r = radix as u128;
+        assert_eq!(sum_twice(&ranges, radix).ok(), reference_part1(&ranges, r));
+        assert_eq!(
+            sum_repeated(&ranges, radix).ok(),
+            reference_part2(&ranges, r)
+        );
+    }
+    assert_eq!(sum_twice(&[(0b1001, 0b1010)], 2), Ok(0b1010));
+    assert!(parse_radix("1-2g", 16).is_err());
+
+    // 0, endpoints beyond 64 bits and sums beyond 128 bits
+    assert_eq!(part2(&[(0, 10)]), Ok(0));
+    let big = parse("99999999000000000000-100000001000000000000").unwrap();
+    assert_eq!(part1(&big), Ok(9999999950499999994950));
+    assert_eq!(summaries(&big, 10).unwrap()[0].count, 100);
+    assert_eq!(part1(&[(0, u128::MAX)]), Err(Overflow));
+    assert_eq!(
+        Total(Err(Overflow)).render(),
+        Err("sum exceeds 128 bits".to_owned())
+    );
+    assert_eq!(Total(Ok(3)).render(), Ok(Some("3".to_owned())));
+    assert!(parse("1-340282366920938463463374607431768211456").is_err());
+
+    let summaries = summaries(&input, 10).unwrap();
+    assert_eq!(
+        summaries.iter().map(|s| s.sum_twice).sum::<u128>(),
+        1227775554
+    );
+    assert_eq!(
+        summaries.iter().map(|s| s.sum_repeated).sum::<u128>(),
+        4174379265
+    );
+    assert_eq!(
+        summaries[1],
+        Summary {
+            begin: 95,
+            end: 115,
+            count: 2,
+            sum_twice: 99,
+            sum_repeated: 210
+        }
+    );
+    assert_eq!(
+        matches_csv(&input[..2], 10),
+        "\
+begin,end,id,block,repetitions,twice
+11,22,11,1,2,true
+11,22,22,1,2,true
+95,115,99,1,2,true
+95,115,111,1,3,false
+"
+    );
+    assert!(summaries_csv(&input, 10).unwrap().starts_with(
+        "begin,end,count,sum_twice,sum_repeated\n11,22,2,33,33\n"
+    ));
+    let ids: Vec<u128> = matches(1, 1200, 10).map(|m| m.id).collect();
+    assert!(ids.is_sorted());
+    assert_eq!(
+        &ids[9..],
+        [111, 222, 333, 444, 555, 666, 777, 888, 999, 1010, 1111]
+    );
+}
@@ -0,0 +1,551 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::Solution;
+use crate::differential::Reference;
+use crate::parse::ParseError;
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.find(|c: char| !c.is_ascii_digit()) {
+            Some(idx) => Err(ParseError::at(input, &s[idx..], "digit")),
+            None => Ok(s.to_string()),
+        })
+        .collect()
+}
+
+/// Whether a selection reads as the largest or the smallest number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Order {
+    #[default]
+    Largest,
+    Smallest,
+}
+
+impl Order {
+    fn prefers(self, digit: u8, other: u8) -> bool {
+        match self {
+            Order::Largest => digit > other,
+            Order::Smallest => digit < other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// Number of digits to choose.
+    pub count: usize,
+    pub order: Order,
+    /// Largest number of digits skipped between two chosen ones.
+    pub max_gap: Option<usize>,
+}
+
+impl Options {
+    /// The largest `count` digits without restrictions on their positions.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            order: Order::Largest,
+            max_gap: None,
+        }
+    }
+}
+
+/// Batteries chosen from a bank.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selection {
+    /// Positions of the chosen digits in the bank, ascending.
+    pub indices: Vec<usize>,
+    /// The chosen digits, which may not fit into any integer type.
+    pub digits: String,
+}
+
+impl Selection {
+    /// The chosen digits read as a number, `None` if it exceeds 64 bits.
+    pub fn value(&self) -> Option<u64> {
+        self.digits.bytes().try_fold(0_u64, |value, digit| {
+            value.checked_mul(10)?.checked_add((digit - b'0') as u64)
+        })
+    }
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.digits)
+    }
+}
+
+/// Picks the digits of `bank` forming the best number according to
+/// `options`, or all of them if the bank is shorter than `options.count`.
+pub fn select(bank: &str, options: Options) -> Selection {
+    let bank = bank.as_bytes();
+
+    let indices = match options.max_gap {
+        Some(gap) if options.count < bank.len() => {
+            select_bounded(bank, options.count, options.order, gap)
+        }
+        _ => select_free(bank, options.count, options.order),
+    };
+    let digits = indices.iter().map(|&idx| bank[idx] as char).collect();
+
+    Selection { indices, digits }
+}
+
+/// A digit drops the worse ones before it as long as enough digits are left
+/// to fill the selection, so every position is pushed and popped at most
+/// once. Ties keep the leftmost digit.
+fn select_free(bank: &[u8], count: usize, order: Order) -> Vec<usize> {
+    let mut stack: Vec<usize> = Vec::with_capacity(count.min(bank.len()));
+
+    for (idx, &digit) in bank.iter().enumerate() {
+        while let Some(&top) = stack.last() {
+            if order.prefers(digit, bank[top])
+                && stack.len() + bank.len() - idx > count
+            {
+                stack.pop();
+            } else {
+                break;
+            }
+        }
+
+        if stack.len() < count {
+            stack.push(idx);
+        }
+    }
+
+    stack
+}
+
+/// Positions in `range` holding `digit` that follow one of the sorted
+/// positions `from` with at most `gap` digits in between, or all positions
+/// holding `digit` if `from` is `None`.
+fn forward(
+    bank: &[u8],
+    from: Option<&[usize]>,
+    digit: u8,
+    gap: usize,
+    range: Range<usize>,
+) -> Vec<usize> {
+    let mut from = from.map(|from| from.iter().peekable());
+    let mut latest = None;
+
+    range
+        .filter(|&idx| {
+            let Some(from) = &mut from else {
+                return bank[idx] == digit;
+            };
+            while let Some(&&p) = from.peek().filter(|&&&p| p < idx) {
+                latest = Some(p);
+                from.next();
+            }
+
+            bank[idx] == digit && latest.is_some_and(|p| idx - p - 1 <= gap)
+        })
+        .collect()
+}
+
+/// The mirror image of `forward`: positions holding `digit` that precede one
+/// of the sorted positions `to`, again sorted.
+fn backward(
+    bank: &[u8],
+    to: Option<&[usize]>,
+    digit: u8,
+    gap: usize,
+    range: Range<usize>,
+) -> Vec<usize> {
+    let mut to = to.map(|to| to.iter().rev().peekable());
+    let mut earliest = None;
+
+    let mut positions: Vec<usize> = range
+        .rev()
+        .filter(|&idx| {
+            let Some(to) = &mut to else {
+                return bank[idx] == digit;
+            };
+            while let Some(&&q) = to.peek().filter(|&&&q| q > idx) {
+                earliest = Some(q);
+                to.next();
+            }
+
+            bank[idx] == digit && earliest.is_some_and(|q| q - idx - 1 <= gap)
+        })
+        .collect();
+
+    positions.reverse();
+    positions
+}
+
+/// Chooses one digit after the other. Equal digits lead to different
+/// reachable positions, so all positions ending the best prefix so far are
+/// kept and the next digit is the best one reachable from any of them. Only
+/// the positions of the previous digit are kept, the indices are recovered
+/// afterwards by `connect`.
+fn select_bounded(
+    bank: &[u8],
+    count: usize,
+    order: Order,
+    gap: usize,
+) -> Vec<usize> {
+    let mut digits = Vec::with_capacity(count);
+    let mut ends: Option<Vec<usize>> = None;
+
+    for step in 0..count {
+        // enough digits have to be left behind the position
+        let last = bank.len() - (count - step);
+
+        let reachable: Vec<usize> = match &ends {
+            None => (0..=last).collect(),
+            Some(prev) => {
+                let mut reachable = vec![];
+                let mut from = prev.iter().peekable();
+                let mut latest = None;
+
+                for idx in 0..=last {
+                    while let Some(&&p) = from.peek().filter(|&&&p| p < idx) {
+                        latest = Some(p);
+                        from.next();
+                    }
+
+                    if latest.is_some_and(|p| idx - p - 1 <= gap) {
+                        reachable.push(idx);
+                    }
+                }
+
+                reachable
+            }
+        };
+
+        let best = reachable
+            .iter()
+            .map(|&idx| bank[idx])
+            .reduce(|best, d| if order.prefers(d, best) { d } else { best })
+            .expect("a consecutive run of digits is always reachable");
+
+        digits.push(best);
+        ends =
+            Some(reachable.into_iter().filter(|&i| bank[i] == best).collect());
+    }
+
+    let mut indices = vec![0; count];
+    connect(bank, &digits, gap, None, None, &mut indices);
+
+    indices
+}
+
+/// Finds positions for `digits` between the positions `start` and `end`,
+/// either of which may be open, and writes them to `indices`. The position
+/// of the middle digit is one both reachable from `start` and reaching `end`,
+/// the halves around it are solved the same way. That keeps the memory
+/// linear in the bank length instead of storing the positions of every step.
+fn connect(
+    bank: &[u8],
+    digits: &[u8],
+    gap: usize,
+    start: Option<usize>,
+    end: Option<usize>,
+    indices: &mut [usize],
+) {
+    if digits.is_empty() {
+        return;
+    }
+
+    let range = start.map_or(0, |p| p + 1)..end.unwrap_or(bank.len());
+    let mid = digits.len() / 2;
+
+    let start_pos = start.map(|p| vec![p]);
+    let mut after =
+        forward(bank, start_pos.as_deref(), digits[0], gap, range.clone());
+    for &digit in &digits[1..=mid] {
+        after = forward(bank, Some(&after), digit, gap, range.clone());
+    }
+
+    let end_pos = end.map(|p| vec![p]);
+    let last = digits.len() - 1;
+    let mut before =
+        backward(bank, end_pos.as_deref(), digits[last], gap, range.clone());
+    for &digit in digits[mid..last].iter().rev() {
+        before = backward(bank, Some(&before), digit, gap, range.clone());
+    }
+
+    let pos = *after
+        .iter()
+        .find(|p| before.binary_search(p).is_ok())
+        .expect("the digits were chosen along a valid selection");
+
+    indices[mid] = pos;
+    let (left, right) = indices.split_at_mut(mid);
+    connect(bank, &digits[..mid], gap, start, Some(pos), left);
+    connect(
+        bank,
+        &digits[mid + 1..],
+        gap,
+        Some(pos),
+        end,
+        &mut right[1..],
+    );
+}
+
+fn add_decimal(a: &str, b: &str) -> String {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+
+    for i in 0..a.len().max(b.len()) {
+        let digit = |n: &[u8]| {
+            n.len().checked_sub(i + 1).map_or(0, |idx| n[idx] - b'0')
+        };
+        let total = digit(a) + digit(b) + carry;
+
+        sum.push(b'0' + total % 10);
+        carry = total / 10;
+    }
+    if carry > 0 {
+        sum.push(b'0' + carry);
+    }
+
+    let digits: String = sum.iter().rev().map(|&d| d as char).collect();
+    match digits.trim_start_matches('0') {
+        "" => "0".to_owned(),
+        trimmed => trimmed.to_owned(),
+    }
+}
+
+/// Sum of the selections of all banks as decimal string, so it never
+/// overflows.
+pub fn total(puzzle: &[String], options: Options) -> String {
+    puzzle.iter().fold("0".to_owned(), |sum, line| {
+        add_decimal(&sum, &select(line, options).digits)
+    })
+}
+
+fn joltage(puzzle: &[String], count: usize) -> i64 {
+    puzzle
+        .iter()
+        .map(|line| select(line, Options::new(count)).value().unwrap() as i64)
+        .sum()
+}
+
+pub fn part1(puzzle: &[String]) -> i64 {
+    joltage(puzzle, 2)
+}
+
+pub fn part2(puzzle: &[String]) -> i64 {
+    joltage(puzzle, 12)
+}
+
+fn digits(line: &str) -> Vec<u64> {
+    line.chars()
+        .flat_map(|d| d.to_digit(10).map(|n| n as u64))
+        .collect()
+}
+
+// tries every pair of batteries
+fn reference_part1(puzzle: &[String]) -> i64 {
+    let mut sum = 0;
+
+    for line in puzzle {
+        let digits = digits(line);
+        let mut best = 0;
+
+        for i in 0..digits.len() {
+            for j in i + 1..digits.len() {
+                best = best.max(digits[i] * 10 + digits[j]);
+            }
+        }
+
+        sum += best as i64;
+    }
+
+    sum
+}
+
+/// Dynamic programming over the digits of a bank from right to left. Only
+/// the best value of every selection size within the suffix seen so far is
+/// kept, so the table has `count + 1` entries however long the bank is and is
+/// reused for every bank.
+struct Table {
+    best: Vec<u64>,
+    powers: Vec<u64>,
+}
+
+impl Table {
+    fn new(count: usize) -> Self {
+        assert!(count <= 19, "selection doesn't fit into 64 bits");
+
+        Self {
+            best: vec![0; count + 1],
+            powers: (0..count as u32).map(|p| 10u64.pow(p)).collect(),
+        }
+    }
+
+    /// Largest number formed by `count` digits of `bank`, or by all of them
+    /// if it is shorter.
+    fn max_value(&mut self, bank: &[u64]) -> u64 {
+        let count = self.powers.len();
+        self.best.fill(0);
+
+        for (seen, &digit) in bank.iter().rev().enumerate() {
+            // larger sizes first, so each digit is used at most once
+            for size in (1..=count.min(seen + 1)).rev() {
+                let used = digit * self.powers[size - 1] + self.best[size - 1];
+                self.best[size] = self.best[size].max(used);
+            }
+        }
+
+        self.best[count.min(bank.len())]
+    }
+}
+
+// tries every selection size for every suffix of the banks
+fn reference_part2(puzzle: &[String]) -> i64 {
+    let mut table = Table::new(12);
+
+    puzzle
+        .iter()
+        .map(|line| table.max_value(&digits(line)) as i64)
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+impl Reference for Day03 {
+    fn reference1(input: &Self::Input) -> Option<Self::Answer1> {
+        Some(reference_part1(input))
+    }
+
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(reference_part2(input))
+    }
+}
+
+#[test]
+fn test_day03() {
+    let input = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 357);
+    assert_eq!(part2(&input), 3121910778619);
+
+    let selection = select(&input[3], Options::new(12));
+    assert_eq!(selection.indices, [0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    assert_eq!(selection.value(), Some(888911112111));
+    assert_eq!(select("54321", Options::new(3)).indices, [0, 1, 2]);
+    assert_eq!(select("1299", Options::new(2)).indices, [2, 3]);
+    assert_eq!(select("123", Options::new(5)).value(), Some(123));
+    assert_eq!(select("123", Options::new(0)).value(), Some(0));
+
+    let smallest = Options {
+        order: Order::Smallest,
+        ..Options::new(3)
+    };
+    assert_eq!(select("540321", smallest).to_string(), "021");
+    let gap = Options {
+        max_gap: Some(1),
+        ..Options::new(2)
+    };
+    assert_eq!(select("9119", gap).to_string(), "91");
+    // the later of two equal digits reaches the better one
+    let gap = Options {
+        max_gap: Some(0),
+        ..Options::new(2)
+    };
+    assert_eq!(select("9091", gap).indices, [2, 3]);
+    let nines = select(
+        &"9".repeat(3000),
+        Options {
+            max_gap: Some(1),
+            ..Options::new(1500)
+        },
+    );
+    assert_eq!(nines.digits, "9".repeat(1500));
+    assert!(nines.indices.windows(2).all(|w| w[1] - w[0] <= 2));
+
+    let long = select(&"98".repeat(15), Options::new(25));
+    assert_eq!(long.digits, "9999998989898989898989898");
+    assert_eq!(long.value(), None);
+    assert_eq!(total(&input, Options::new(12)), "3121910778619");
+    assert_eq!(
+        total(&["9".repeat(30), "1".into()], Options::new(30)),
+        format!("1{}", "0".repeat(30))
+    );
+
+    // every subset of short banks
+    let mut rng = crate::rng::Rng::new(5);
+    for _ in 0..200 {
+        let bank: String = (0..rng.range(1..=10))
+            .map(|_| char::from(b'0' + rng.range(0..=9) as u8))
+            .collect();
+        let count = rng.range(1..=bank.len() as u64) as usize;
+        let options = Options {
+            count,
+            order: [Order::Largest, Order::Smallest][rng.index(2)],
+            max_gap: [None, Some(0), Some(1), Some(2)][rng.index(4)],
+        };
+
+        let brute = (0_u32..1 << bank.len())
+            .filter(|set| set.count_ones() as usize == count)
+            .map(|set| (0..bank.len()).filter(|i| set & 1 << i != 0).collect())
+            .filter(|idx: &Vec<usize>| {
+                idx.windows(2).all(|w| {
+                    options.max_gap.is_none_or(|gap| w[1] - w[0] - 1 <= gap)
+                })
+            })
+            .map(|idx| idx.iter().map(|&i| &bank[i..=i]).collect::<String>());
+        let brute = match options.order {
+            Order::Largest => brute.max(),
+            Order::Smallest => brute.min(),
+        };
+
+        let selection = select(&bank, options);
+        assert_eq!(Some(&selection.digits), brute.as_ref(), "{options:?}");
+        assert!(selection.indices.windows(2).all(|w| {
+            options.max_gap.is_none_or(|gap| w[1] - w[0] - 1 <= gap)
+        }));
+    }
+
+    let mut rng = crate::rng::Rng::new(3);
+    let mut table = Table::new(12);
+    for size in 0..50 {
+        let banks = crate::generator::day03::generate(&mut rng, size);
+        for bank in banks.lines() {
+            let selection = select(bank, Options::new(12));
+
+            assert_eq!(selection.value(), Some(table.max_value(&digits(bank))));
+            assert!(selection.indices.is_sorted());
+        }
+    }
+
+    let bank: String = (0..300_000)
+        .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+        .collect();
+    assert_eq!(
+        select(&bank, Options::new(12)).value(),
+        Some(table.max_value(&digits(&bank)))
+    );
+}
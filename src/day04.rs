@@ -0,0 +1,858 @@
+use crate::Solution;
+use crate::differential::Reference;
+use crate::parse::{self, ParseError};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::grid(input, &['.', '@'], "`.` or `@`")
+}
+
+fn access(field: &[Vec<char>], x: isize, y: isize) -> i64 {
+    if x < 0 || y < 0 {
+        return 0;
+    }
+
+    let elem = field.get(y as usize).and_then(|line| line.get(x as usize));
+    if let Some(&'@') = elem { 1 } else { 0 }
+}
+
+/// Cells around a roll that are looked at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The square around the cell.
+    #[default]
+    Moore,
+    /// The cells within the Manhattan distance of the radius.
+    VonNeumann,
+}
+
+/// How the number of rolls around a roll is compared to the threshold.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Comparison {
+    #[default]
+    Below,
+    AtMost,
+    Exactly,
+    AtLeast,
+    Above,
+}
+
+/// What lies beyond the field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Edge {
+    /// Nothing, as in the puzzle.
+    #[default]
+    Empty,
+    /// Rolls that are never removed.
+    Wall,
+    /// The opposite side of the field.
+    Wrap,
+}
+
+/// When a roll can be reached by a forklift.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub neighborhood: Neighborhood,
+    pub radius: usize,
+    pub comparison: Comparison,
+    pub threshold: u32,
+    pub edge: Edge,
+}
+
+impl Default for Rule {
+    /// Fewer than four rolls among the eight cells around.
+    fn default() -> Self {
+        Self {
+            neighborhood: Neighborhood::Moore,
+            radius: 1,
+            comparison: Comparison::Below,
+            threshold: 4,
+            edge: Edge::Empty,
+        }
+    }
+}
+
+impl Rule {
+    /// Whether a roll with `rolls` rolls around it is accessible.
+    pub fn accessible(&self, rolls: u32) -> bool {
+        match self.comparison {
+            Comparison::Below => rolls < self.threshold,
+            Comparison::AtMost => rolls <= self.threshold,
+            Comparison::Exactly => rolls == self.threshold,
+            Comparison::AtLeast => rolls >= self.threshold,
+            Comparison::Above => rolls > self.threshold,
+        }
+    }
+
+    /// Positions of the neighbors relative to the cell.
+    fn offsets(&self) -> Vec<(isize, isize)> {
+        let r = self.radius as isize;
+
+        (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter(|(dx, dy)| {
+                self.neighborhood == Neighborhood::Moore
+                    || dx.abs() + dy.abs() <= r
+            })
+            .collect()
+    }
+}
+
+/// Shape of the field and the neighbors of its cells under a rule.
+struct Geometry {
+    width: usize,
+    height: usize,
+    radius: usize,
+    edge: Edge,
+    offsets: Vec<(isize, isize)>,
+    /// The offsets as index differences, for cells away from the edge.
+    steps: Vec<isize>,
+}
+
+impl Geometry {
+    fn new(width: usize, height: usize, rule: &Rule) -> Self {
+        let offsets = rule.offsets();
+        let steps = offsets
+            .iter()
+            .map(|&(dx, dy)| dy * width as isize + dx)
+            .collect();
+
+        Self {
+            width,
+            height,
+            radius: rule.radius,
+            edge: rule.edge,
+            offsets,
+            steps,
+        }
+    }
+
+    /// Calls `f` with the index of every neighbor of `idx`, `None` for
+    /// neighbors beyond an edge that doesn't wrap. With wrapping, small
+    /// fields can contain the same cell several times.
+    fn neighbors(&self, idx: usize, mut f: impl FnMut(Option<usize>)) {
+        let (x, y) = (idx % self.width, idx / self.width);
+        let r = self.radius;
+
+        if x >= r && y >= r && x + r < self.width && y + r < self.height {
+            for &step in &self.steps {
+                f(Some(idx.wrapping_add_signed(step)));
+            }
+            return;
+        }
+
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        for &(dx, dy) in &self.offsets {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+
+            if (0..width).contains(&nx) && (0..height).contains(&ny) {
+                f(Some((ny * width + nx) as usize));
+            } else if self.edge == Edge::Wrap {
+                let (nx, ny) = (nx.rem_euclid(width), ny.rem_euclid(height));
+                f(Some((ny * width + nx) as usize));
+            } else {
+                f(None);
+            }
+        }
+    }
+
+    /// Number of rolls around every roll, zero for the other cells.
+    fn counts(&self, rolls: &[bool]) -> Vec<u32> {
+        (0..rolls.len())
+            .map(|idx| {
+                let mut count = 0;
+
+                if rolls[idx] {
+                    self.neighbors(idx, |neighbor| match neighbor {
+                        Some(n) => count += rolls[n] as u32,
+                        None => count += (self.edge == Edge::Wall) as u32,
+                    });
+                }
+
+                count
+            })
+            .collect()
+    }
+}
+
+fn rolls(puzzle: &[Vec<char>]) -> Vec<bool> {
+    puzzle.iter().flatten().map(|&c| c == '@').collect()
+}
+
+/// Number of rolls accessible under `rule`.
+pub fn accessible(puzzle: &[Vec<char>], rule: &Rule) -> usize {
+    let rolls = rolls(puzzle);
+    let width = puzzle.first().map_or(0, Vec::len);
+
+    Geometry::new(width, puzzle.len(), rule)
+        .counts(&rolls)
+        .iter()
+        .zip(&rolls)
+        .filter(|&(&count, &roll)| roll && rule.accessible(count))
+        .count()
+}
+
+/// Rolls packed into 64 bit words row by row, so the rolls around 64 cells
+/// are counted at once with shifts and bitwise adders. Only handles the rule
+/// of the puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words per row, the bits past the width are always cleared.
+    words: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(puzzle: &[Vec<char>]) -> Self {
+        let width = puzzle.first().map_or(0, Vec::len);
+
+        Self::pack(
+            width,
+            puzzle.iter().map(|row| row.iter().map(|&c| c == '@')),
+        )
+    }
+
+    /// Parses the puzzle input without storing it as characters first.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rows = parse::grid_rows(input, &['.', '@'], "`.` or `@`")?;
+        let width = rows.first().map_or(0, |row| row.len());
+
+        Ok(Self::pack(
+            width,
+            rows.iter().map(|row| row.bytes().map(|b| b == b'@')),
+        ))
+    }
+
+    /// Packs rows of `width` cells, `true` for a roll.
+    fn pack(
+        width: usize,
+        rows: impl Iterator<Item = impl Iterator<Item = bool>>,
+    ) -> Self {
+        let words = width.div_ceil(64);
+        let mut bits = vec![];
+        let mut height = 0;
+
+        for row in rows {
+            bits.resize(bits.len() + words, 0);
+            let packed = &mut bits[height * words..];
+
+            for (x, roll) in row.enumerate() {
+                packed[x / 64] |= (roll as u64) << (x % 64);
+            }
+
+            height += 1;
+        }
+
+        Self {
+            width,
+            height,
+            words,
+            bits,
+        }
+    }
+
+    /// The field as characters, for the cell-based engine.
+    fn field(&self) -> Vec<Vec<char>> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| {
+                        let word = self.bits[y * self.words + x / 64];
+                        if word >> (x % 64) & 1 == 1 { '@' } else { '.' }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.bits[y * self.words..(y + 1) * self.words]
+    }
+
+    /// Rolls of word `i` of row `y` with fewer than four rolls around.
+    fn accessible_word(&self, y: usize, i: usize) -> u64 {
+        let above = y.checked_sub(1).map(|y| self.row(y));
+        let below = (y + 1 < self.height).then(|| self.row(y + 1));
+        let row = self.row(y);
+
+        // bit planes of the count, at most eight needs four of them
+        let mut planes = [0_u64; 4];
+        let mut add = |mut carry: u64| {
+            for plane in &mut planes {
+                let next = *plane & carry;
+                *plane ^= carry;
+                carry = next;
+            }
+        };
+
+        for line in [above, below].into_iter().flatten() {
+            add(west(line, i));
+            add(line[i]);
+            add(east(line, i));
+        }
+        add(west(row, i));
+        add(east(row, i));
+
+        row[i] & !(planes[2] | planes[3])
+    }
+
+    /// Number of accessible rolls.
+    pub fn accessible(&self) -> usize {
+        (0..self.bits.len())
+            .map(|w| {
+                let word = self.accessible_word(w / self.words, w % self.words);
+                word.count_ones() as usize
+            })
+            .sum()
+    }
+
+    /// Removes all accessible rolls round after round and returns how many
+    /// were removed. Only the words around a word that lost rolls are looked
+    /// at again, so the work is linear in the size of the field and the
+    /// number of removed rolls.
+    pub fn remove_all(&mut self) -> usize {
+        let mut dirty: Vec<usize> = (0..self.bits.len()).collect();
+        let mut queued = vec![true; self.bits.len()];
+        let mut removed = 0;
+
+        loop {
+            let mut changed = vec![];
+
+            for &w in &dirty {
+                queued[w] = false;
+
+                let mask = self.accessible_word(w / self.words, w % self.words);
+                if mask != 0 {
+                    changed.push((w, mask));
+                }
+            }
+
+            if changed.is_empty() {
+                return removed;
+            }
+
+            dirty.clear();
+            for (w, mask) in changed {
+                self.bits[w] &= !mask;
+                removed += mask.count_ones() as usize;
+
+                // the words to the sides only see the outermost bits
+                let (y, i) = (w / self.words, w % self.words);
+                let left = i - (i > 0 && mask & 1 != 0) as usize;
+                let right =
+                    i + (i + 1 < self.words && mask >> 63 != 0) as usize;
+
+                for y in y.saturating_sub(1)..(y + 2).min(self.height) {
+                    for i in left..=right {
+                        let w = y * self.words + i;
+
+                        if !queued[w] {
+                            queued[w] = true;
+                            dirty.push(w);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Cells whose west neighbor holds a roll, for word `i` of `row`.
+fn west(row: &[u64], i: usize) -> u64 {
+    let carry = if i > 0 { row[i - 1] >> 63 } else { 0 };
+    row[i] << 1 | carry
+}
+
+/// Cells whose east neighbor holds a roll, for word `i` of `row`.
+fn east(row: &[u64], i: usize) -> u64 {
+    let carry = row.get(i + 1).map_or(0, |w| w << 63);
+    row[i] >> 1 | carry
+}
+
+pub fn part1(puzzle: &BitGrid) -> i64 {
+    puzzle.accessible() as i64
+}
+
+/// Rolls removed in every round, as indices into the field, when all
+/// accessible rolls are removed at once. Only the neighbors of removed rolls
+/// can change, so they are the only ones looked at for the next round.
+fn waves(puzzle: &[Vec<char>], rule: &Rule) -> Vec<Vec<usize>> {
+    let mut rolls = rolls(puzzle);
+    let width = puzzle.first().map_or(0, Vec::len);
+    let geometry = Geometry::new(width, puzzle.len(), rule);
+    let mut counts = geometry.counts(&rolls);
+
+    let mut wave: Vec<usize> = (0..rolls.len())
+        .filter(|&idx| rolls[idx] && rule.accessible(counts[idx]))
+        .collect();
+    let mut touched = vec![false; rolls.len()];
+    let mut waves = vec![];
+
+    while !wave.is_empty() {
+        for &idx in &wave {
+            rolls[idx] = false;
+        }
+
+        let mut changed = vec![];
+        for &idx in &wave {
+            geometry.neighbors(idx, |neighbor| {
+                if let Some(n) = neighbor.filter(|&n| rolls[n]) {
+                    counts[n] -= 1;
+
+                    if !touched[n] {
+                        touched[n] = true;
+                        changed.push(n);
+                    }
+                }
+            });
+        }
+
+        for &idx in &changed {
+            touched[idx] = false;
+        }
+
+        waves.push(wave);
+        wave = changed
+            .into_iter()
+            .filter(|&idx| rule.accessible(counts[idx]))
+            .collect();
+    }
+
+    waves
+}
+
+/// Number of rolls removed under `rule` until none is accessible anymore.
+pub fn removable(puzzle: &[Vec<char>], rule: &Rule) -> usize {
+    waves(puzzle, rule).iter().map(Vec::len).sum()
+}
+
+pub fn part2(puzzle: &BitGrid) -> i64 {
+    puzzle.clone().remove_all() as i64
+}
+
+/// The rounds in which the rolls are removed, for inspecting how the pile
+/// erodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct History {
+    width: usize,
+    rolls: Vec<bool>,
+    generations: Vec<Option<usize>>,
+    waves: usize,
+}
+
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+impl History {
+    pub fn new(puzzle: &[Vec<char>], rule: &Rule) -> Self {
+        let rolls = rolls(puzzle);
+        let waves = waves(puzzle, rule);
+        let mut generations = vec![None; rolls.len()];
+
+        for (generation, wave) in waves.iter().enumerate() {
+            for &idx in wave {
+                generations[idx] = Some(generation + 1);
+            }
+        }
+
+        Self {
+            width: puzzle.first().map_or(0, Vec::len),
+            rolls,
+            generations,
+            waves: waves.len(),
+        }
+    }
+
+    /// Number of rounds until no roll is accessible anymore.
+    pub fn waves(&self) -> usize {
+        self.waves
+    }
+
+    /// Round, starting at 1, in which the roll at `(x, y)` is removed. `None`
+    /// for empty cells, rolls that are never removed and positions outside
+    /// the field.
+    pub fn generation(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height() {
+            return None;
+        }
+
+        self.generations[y * self.width + x]
+    }
+
+    /// The field after `wave` rounds.
+    pub fn frame(&self, wave: usize) -> Vec<Vec<char>> {
+        (0..self.height())
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| match self.cell(y * self.width + x, wave) {
+                        Some(_) => '@',
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Grayscale image in plain PGM format with the round in which each roll
+    /// is removed as brightness. Empty cells are black, rolls that stay are
+    /// white. Plain PGM allows at most 65535 levels, more rounds are scaled
+    /// down.
+    pub fn pgm(&self) -> String {
+        let white = (self.waves + 1).min(65535);
+        // rounds map to 1..white, so removed rolls never look empty
+        let level = |g: usize| 1 + (g - 1) * (white - 1) / self.waves;
+        let mut pgm =
+            format!("P2\n{} {}\n{white}\n", self.width, self.height());
+
+        for y in 0..self.height() {
+            let row: Vec<String> = (0..self.width)
+                .map(|x| y * self.width + x)
+                .map(|idx| match self.generations[idx] {
+                    Some(g) => level(g).to_string(),
+                    None if self.rolls[idx] => white.to_string(),
+                    None => "0".to_owned(),
+                })
+                .collect();
+
+            pgm += &(row.join(" ") + "\n");
+        }
+
+        pgm
+    }
+
+    fn height(&self) -> usize {
+        self.rolls.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    /// Cell of the field after `wave` rounds: `None` if empty, otherwise
+    /// whether the roll is removed in the next round.
+    fn cell(&self, idx: usize, wave: usize) -> Option<bool> {
+        match self.generations[idx] {
+            Some(g) if g <= wave => None,
+            Some(g) => Some(g == wave + 1),
+            None => self.rolls[idx].then_some(false),
+        }
+    }
+
+    /// One image in plain PPM format per round, from the initial field to the
+    /// one left over. Rolls are white, the ones removed in the next round red.
+    pub fn ppm_frames(&self) -> Vec<String> {
+        (0..=self.waves)
+            .map(|wave| {
+                let mut ppm =
+                    format!("P3\n{} {}\n255\n", self.width, self.height());
+
+                for y in 0..self.height() {
+                    let row: Vec<&str> = (0..self.width)
+                        .map(|x| match self.cell(y * self.width + x, wave) {
+                            None => "0 0 0",
+                            Some(false) => "255 255 255",
+                            Some(true) => "255 0 0",
+                        })
+                        .collect();
+
+                    ppm += &(row.join(" ") + "\n");
+                }
+
+                ppm
+            })
+            .collect()
+    }
+
+    /// The same frames for a terminal. Each one moves the cursor to the top
+    /// left corner first, so printing them with a short delay animates the
+    /// field in place.
+    pub fn ansi_frames(&self) -> Vec<String> {
+        (0..=self.waves)
+            .map(|wave| {
+                let mut frame = "\x1b[H".to_owned();
+
+                for y in 0..self.height() {
+                    for x in 0..self.width {
+                        match self.cell(y * self.width + x, wave) {
+                            None => frame.push('.'),
+                            Some(false) => frame.push('@'),
+                            Some(true) => frame += &format!("{RED}@{RESET}"),
+                        }
+                    }
+                    frame.push('\n');
+                }
+
+                frame
+            })
+            .collect()
+    }
+}
+
+fn rolls_around(field: &[Vec<char>], x: isize, y: isize) -> i64 {
+    access(field, x, y - 1)
+        + access(field, x, y + 1)
+        + access(field, x - 1, y - 1)
+        + access(field, x - 1, y)
+        + access(field, x - 1, y + 1)
+        + access(field, x + 1, y - 1)
+        + access(field, x + 1, y)
+        + access(field, x + 1, y + 1)
+}
+
+// looks at the eight cells around every roll
+fn reference_part1(puzzle: &[Vec<char>]) -> i64 {
+    let mut sum = 0;
+
+    for y in 0..puzzle.len() {
+        for x in 0..puzzle[0].len() {
+            if puzzle[y][x] == '@'
+                && rolls_around(puzzle, x as isize, y as isize) < 4
+            {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+// rescans the whole field every round
+fn reference_part2(puzzle: &[Vec<char>]) -> Option<i64> {
+    if puzzle.len() * puzzle.first().map_or(0, Vec::len) > 1_000_000 {
+        return None;
+    }
+
+    let mut sum = 0;
+
+    let mut field = puzzle.to_vec();
+
+    loop {
+        let mut to_remove = vec![];
+
+        for y in 0..field.len() {
+            for x in 0..field[0].len() {
+                if field[y][x] == '@'
+                    && rolls_around(&field, x as isize, y as isize) < 4
+                {
+                    to_remove.push((x, y));
+                    sum += 1;
+                }
+            }
+        }
+
+        if to_remove.is_empty() {
+            break;
+        }
+
+        for &(x, y) in &to_remove {
+            field[y][x] = '.';
+        }
+    }
+
+    Some(sum)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = BitGrid;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        BitGrid::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+impl Reference for Day04 {
+    fn reference1(input: &Self::Input) -> Option<Self::Answer1> {
+        Some(reference_part1(&input.field()))
+    }
+
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2> {
+        reference_part2(&input.field())
+    }
+}
+
+#[test]
+fn test_day04() {
+    let input = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+    let grid = BitGrid::parse(input).unwrap();
+    let input = parse(input).unwrap();
+
+    assert_eq!(grid, BitGrid::new(&input));
+    assert_eq!(part1(&grid), 13);
+    assert_eq!(part2(&grid), 43);
+    assert_eq!(reference_part2(&grid.field()), Some(43));
+    assert_eq!(part2(&BitGrid::parse("@").unwrap()), 1);
+    assert_eq!(part2(&BitGrid::parse("").unwrap()), 0);
+    assert_eq!(
+        BitGrid::parse("..@\n.@\n").unwrap_err(),
+        parse("..@\n.@\n").unwrap_err()
+    );
+
+    let history = History::new(&input, &Rule::default());
+    assert_eq!(history.waves(), 9);
+    assert_eq!(history.generation(2, 0), Some(1));
+    assert_eq!(history.generation(0, 0), None);
+    assert_eq!(history.frame(0), input);
+    assert_eq!(
+        history
+            .frame(1)
+            .iter()
+            .flatten()
+            .filter(|&&c| c == '@')
+            .count(),
+        71 - 13
+    );
+    assert_eq!(history.frame(9), history.frame(100));
+    assert!(history.pgm().starts_with("P2\n10 10\n10\n0 0 1 1 0 "));
+    assert_eq!(history.generation(10, 0), None);
+    assert_eq!(history.generation(0, 10), None);
+
+    let long = History {
+        width: 3,
+        rolls: vec![true, true, false],
+        generations: vec![Some(1), Some(99_999), None],
+        waves: 99_999,
+    };
+    assert_eq!(long.pgm(), "P2\n3 1\n65535\n1 65534 0\n");
+
+    let frames = history.ppm_frames();
+    assert_eq!(frames.len(), 10);
+    assert!(frames[0].starts_with("P3\n10 10\n255\n0 0 0 0 0 0 255 0 0 "));
+    let frames = history.ansi_frames();
+    assert_eq!(frames[0].matches("\x1b[31m@").count(), 13);
+    assert!(!frames[9].contains("\x1b[31m"));
+
+    let cross = Rule {
+        neighborhood: Neighborhood::VonNeumann,
+        threshold: 2,
+        ..Rule::default()
+    };
+    assert_eq!(Rule::default().offsets().len(), 8);
+    assert_eq!(Rule { radius: 2, ..cross }.offsets().len(), 12);
+    let block = parse("@@@\n@@@\n@@@").unwrap();
+    assert_eq!(accessible(&block, &cross), 0);
+    assert_eq!(removable(&block, &cross), 0);
+    let wall = Rule {
+        edge: Edge::Wall,
+        ..Rule::default()
+    };
+    assert_eq!(removable(&block, &wall), 0);
+    let wrap = Rule {
+        edge: Edge::Wrap,
+        ..Rule::default()
+    };
+    assert_eq!(accessible(&parse("@.\n..").unwrap(), &wrap), 1);
+    let crowded = Rule {
+        comparison: Comparison::AtLeast,
+        threshold: 8,
+        edge: Edge::Wrap,
+        ..Rule::default()
+    };
+    assert_eq!(removable(&block, &crowded), 9);
+
+    // words of the bit grid are crossed horizontally
+    let mut rng = crate::rng::Rng::new(6);
+    for size in [62, 63, 64, 65, 127, 130] {
+        let field = parse(&crate::generator::day04::generate(&mut rng, size));
+        let field = field.unwrap();
+        let rule = Rule::default();
+
+        let grid = BitGrid::new(&field);
+        assert_eq!(grid.accessible(), accessible(&field, &rule));
+        assert_eq!(grid.clone().remove_all(), removable(&field, &rule));
+    }
+
+    // a single roll is removed per round along the cascade
+    let mut chain = vec![vec!['@'; 200]; 3];
+    chain[1][0] = '.';
+    let grid = BitGrid::new(&chain);
+    assert_eq!(grid.field(), chain);
+    assert_eq!(part2(&grid), removable(&chain, &Rule::default()) as i64);
+
+    // rounds simulated cell by cell for random rules
+    let mut rng = crate::rng::Rng::new(4);
+    for _ in 0..300 {
+        let size = rng.index(6);
+        let input = crate::generator::day04::generate(&mut rng, size);
+        let mut field = parse(&input).unwrap();
+        let size = field.len() as isize;
+        let rule = Rule {
+            neighborhood: [Neighborhood::Moore, Neighborhood::VonNeumann]
+                [rng.index(2)],
+            radius: rng.index(3),
+            comparison: [
+                Comparison::Below,
+                Comparison::AtMost,
+                Comparison::Exactly,
+                Comparison::AtLeast,
+                Comparison::Above,
+            ][rng.index(5)],
+            threshold: rng.range(0..=6) as u32,
+            edge: [Edge::Empty, Edge::Wall, Edge::Wrap][rng.index(3)],
+        };
+
+        let count = |field: &[Vec<char>], x: isize, y: isize| {
+            rule.offsets()
+                .iter()
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .filter(|&(nx, ny)| {
+                    let inside =
+                        (0..size).contains(&nx) && (0..size).contains(&ny);
+
+                    if inside || rule.edge == Edge::Wrap {
+                        let (nx, ny) =
+                            (nx.rem_euclid(size), ny.rem_euclid(size));
+                        field[ny as usize][nx as usize] == '@'
+                    } else {
+                        rule.edge == Edge::Wall
+                    }
+                })
+                .count() as u32
+        };
+
+        let mut removed = 0;
+        loop {
+            let round: Vec<(usize, usize)> = (0..size)
+                .flat_map(|y| (0..size).map(move |x| (x, y)))
+                .filter(|&(x, y)| {
+                    field[y as usize][x as usize] == '@'
+                        && rule.accessible(count(&field, x, y))
+                })
+                .map(|(x, y)| (x as usize, y as usize))
+                .collect();
+
+            if round.is_empty() {
+                break;
+            }
+            for &(x, y) in &round {
+                field[y][x] = '.';
+            }
+            removed += round.len();
+        }
+
+        let field = parse(&input).unwrap();
+        assert_eq!(removable(&field, &rule), removed, "{rule:?}");
+    }
+}
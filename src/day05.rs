@@ -0,0 +1,163 @@
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+use crate::parse::{self, ParseError};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Interval {
+    begin: i64,
+    end: i64,
+}
+
+impl Interval {
+    fn from_range(range: RangeInclusive<i64>) -> Self {
+        Self {
+            begin: *range.start(),
+            end: *range.end(),
+        }
+    }
+
+    fn union(&self, other: &Interval) -> Option<Self> {
+        if self.end < other.begin || other.end < self.begin {
+            None
+        } else {
+            Some(Self::from_range(
+                self.begin.min(other.begin)..=self.end.max(other.end),
+            ))
+        }
+    }
+
+    fn size(&self) -> i64 {
+        self.end - self.begin + 1
+    }
+
+    fn contains(&self, num: i64) -> bool {
+        self.begin <= num && num <= self.end
+    }
+}
+
+pub struct Puzzle {
+    fresh: Vec<Interval>,
+    ingredients: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let (fresh, ingredients) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::eof(input, "empty line"))?;
+
+    let fresh = fresh
+        .lines()
+        .map(|l| {
+            let (begin, end) = l
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, &l[l.len()..], "`-`"))?;
+
+            Ok(Interval {
+                begin: parse::number(input, begin, "number")?,
+                end: parse::number(input, end, "number")?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let ingredients = ingredients
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| parse::number(input, l, "number"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Puzzle { fresh, ingredients })
+}
+
+pub fn part1(puzzle: &Puzzle) -> i64 {
+    let mut sum = 0;
+
+    'outer: for i in &puzzle.ingredients {
+        for interval in &puzzle.fresh {
+            if interval.contains(*i) {
+                sum += 1;
+                continue 'outer;
+            }
+        }
+    }
+
+    sum
+}
+
+pub fn part2(puzzle: &Puzzle) -> i64 {
+    let mut combined_intervals = vec![];
+
+    let mut fresh = puzzle.fresh.clone();
+    // ensure intervals are combined by avoiding gaps because of the order
+    fresh.sort_by_key(|interval| interval.begin);
+
+    for interval in &fresh {
+        let mut found = false;
+        for combined in combined_intervals.iter_mut() {
+            if let Some(new) = interval.union(combined) {
+                *combined = new;
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            combined_intervals.push(*interval);
+        }
+    }
+
+    let mut sum = 0;
+    for interval in &combined_intervals {
+        sum += interval.size();
+    }
+
+    sum
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Puzzle;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_day05() {
+    let input = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+    let input = parse(input).unwrap();
+
+    assert_eq!(
+        Interval::from_range(10..=18)
+            .union(&Interval::from_range(16..=20))
+            .unwrap(),
+        Interval::from_range(10..=20)
+    );
+    assert_eq!(part1(&input), 3);
+    assert_eq!(part2(&input), 14);
+}
@@ -0,0 +1,164 @@
+use crate::Solution;
+use crate::parse::{self, ParseError};
+
+#[derive(Debug)]
+pub enum Expr {
+    Sum(Vec<i64>),
+    Prod(Vec<i64>),
+}
+
+impl Expr {
+    fn eval(&self) -> i64 {
+        match self {
+            Expr::Sum(items) => items.iter().sum(),
+            Expr::Prod(items) => items.iter().product(),
+        }
+    }
+
+    fn add_num(&mut self, num: i64) {
+        let nums = match self {
+            Expr::Sum(nums) | Expr::Prod(nums) => nums,
+        };
+
+        nums.push(num);
+    }
+}
+
+pub fn parse1(input: &str) -> Result<Vec<Expr>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let table: Vec<Vec<&str>> = lines
+        .iter()
+        .map(|l| l.split_ascii_whitespace().collect())
+        .collect();
+
+    let Some(ops) = table.last() else {
+        return Err(ParseError::eof(input, "number"));
+    };
+
+    // every row needs a number for each operator
+    for (line, row) in lines.iter().zip(&table) {
+        if row.len() < ops.len() {
+            return Err(ParseError::at(input, &line[line.len()..], "number"));
+        } else if row.len() > ops.len() {
+            return Err(ParseError::at(input, row[ops.len()], "end of line"));
+        }
+    }
+
+    let mut exprs = vec![];
+
+    for col in 0..ops.len() {
+        let mut nums: Vec<i64> = vec![];
+        for row in table.iter().take(table.len() - 1) {
+            nums.push(parse::number(input, row[col], "number")?);
+        }
+
+        match ops[col] {
+            "+" => exprs.push(Expr::Sum(nums)),
+            "*" => exprs.push(Expr::Prod(nums)),
+            op => return Err(ParseError::at(input, op, "`+` or `*`")),
+        }
+    }
+
+    Ok(exprs)
+}
+
+pub fn solve(puzzle: &[Expr]) -> i64 {
+    puzzle.iter().map(Expr::eval).sum()
+}
+
+pub fn parse2(input: &str) -> Result<Vec<Expr>, ParseError> {
+    let text: Vec<&str> = input.lines().collect();
+
+    let Some((ops, rows)) = text.split_last() else {
+        return Err(ParseError::eof(input, "number"));
+    };
+
+    for row in rows {
+        if let Some(idx) = row.find(|c: char| c != ' ' && !c.is_ascii_digit()) {
+            return Err(ParseError::at(input, &row[idx..], "digit"));
+        }
+    }
+
+    if let Some(idx) = ops.find(|c| !matches!(c, ' ' | '+' | '*')) {
+        return Err(ParseError::at(input, &ops[idx..], "`+` or `*`"));
+    }
+
+    let lines: Vec<_> = text.iter().map(|l| l.as_bytes()).collect();
+
+    // trailing spaces may be missing on some lines
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    let bottom = lines.len() - 1;
+    let cell = |h: usize, w: usize| lines[h].get(w).copied().unwrap_or(b' ');
+
+    let mut exprs = vec![];
+
+    for w in 0..width {
+        if (0..=bottom).all(|h| cell(h, w) == b' ') {
+            continue;
+        }
+
+        if cell(bottom, w) != b' ' {
+            // new expr
+            if cell(bottom, w) == b'+' {
+                exprs.push(Expr::Sum(vec![]));
+            } else {
+                exprs.push(Expr::Prod(vec![]));
+            }
+        }
+
+        let mut num = 0;
+        for h in 0..bottom {
+            if cell(h, w).is_ascii_digit() {
+                num = num * 10 + (cell(h, w) - b'0') as i64;
+            }
+        }
+
+        match exprs.last_mut() {
+            Some(e) => e.add_num(num),
+            None => {
+                let at = &ops[w.min(ops.len())..];
+                return Err(ParseError::at(input, at, "`+` or `*`"));
+            }
+        }
+    }
+
+    Ok(exprs)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    // both parts read the worksheet differently
+    type Input = (Vec<Expr>, Vec<Expr>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse1(input)?, parse2(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        solve(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        solve(&input.1)
+    }
+}
+
+#[test]
+fn test_day06() {
+    let input = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    assert_eq!(solve(&parse1(input).unwrap()), 4277556);
+    assert_eq!(solve(&parse2(input).unwrap()), 3263827);
+
+    let err = parse1("1 2\n3\n+ *").unwrap_err();
+    assert_eq!((err.line, err.column, err.expected), (2, 2, "number"));
+}
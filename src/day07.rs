@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Solution;
+use crate::differential::Reference;
+use crate::parse::{self, ParseError};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let grid = parse::grid(input, &['.', '^', 'S'], "`.`, `^` or `S`")?;
+
+    if !grid.first().is_some_and(|line| line.contains(&'S')) {
+        let first = input.lines().next().unwrap_or(input);
+        return Err(ParseError::at(input, &first[first.len()..], "`S`"));
+    }
+
+    Ok(grid)
+}
+
+pub fn part1(puzzle: &Vec<Vec<char>>) -> i64 {
+    let start = puzzle[0].iter().position(|c| *c == 'S').unwrap();
+
+    let mut rays = vec![false; puzzle[0].len()];
+
+    rays[start] = true;
+
+    let mut split = 0;
+
+    for line in puzzle {
+        let mut new_rays = vec![false; line.len()];
+
+        for (ray, active) in rays.iter().enumerate() {
+            if !active {
+                continue;
+            }
+
+            match line[ray] {
+                '.' | 'S' => new_rays[ray] = true,
+                '^' => {
+                    split += 1;
+                    new_rays[ray - 1] = true;
+                    new_rays[ray + 1] = true;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        std::mem::swap(&mut rays, &mut new_rays);
+    }
+
+    split
+}
+
+pub fn part2(puzzle: &Vec<Vec<char>>) -> i64 {
+    let start = puzzle[0].iter().position(|c| *c == 'S').unwrap();
+
+    let mut cache = HashMap::new();
+
+    fn recur(
+        puzzle: &Vec<Vec<char>>,
+        ray: usize,
+        depth: usize,
+        cache: &mut HashMap<(usize, usize), i64>,
+    ) -> i64 {
+        if let Some(res) = cache.get(&(ray, depth)) {
+            return *res;
+        }
+
+        if depth >= puzzle.len() {
+            return 1;
+        }
+
+        let res = match puzzle[depth][ray] {
+            '.' | 'S' => recur(puzzle, ray, depth + 1, cache),
+            '^' => {
+                recur(puzzle, ray - 1, depth + 1, cache)
+                    + recur(puzzle, ray + 1, depth + 1, cache)
+            }
+            _ => unreachable!(),
+        };
+
+        cache.insert((ray, depth), res);
+
+        res
+    }
+
+    recur(puzzle, start, 0, &mut cache)
+}
+
+fn start(puzzle: &[Vec<char>]) -> usize {
+    puzzle[0].iter().position(|c| *c == 'S').unwrap()
+}
+
+// follows every beam separately, beams leaving the manifold are dropped
+fn reference_part1(puzzle: &[Vec<char>]) -> i64 {
+    let width = puzzle[0].len();
+    let mut seen = HashSet::new();
+    let mut beams = vec![(0, start(puzzle))];
+    let mut split = 0;
+
+    while let Some((y, x)) = beams.pop() {
+        if y >= puzzle.len() || !seen.insert((y, x)) {
+            continue;
+        }
+
+        if puzzle[y][x] == '^' {
+            split += 1;
+            beams.extend(x.checked_sub(1).map(|x| (y + 1, x)));
+            beams.extend((x + 1 < width).then_some((y + 1, x + 1)));
+        } else {
+            beams.push((y + 1, x));
+        }
+    }
+
+    split
+}
+
+// counts the timelines per column row by row
+fn reference_part2(puzzle: &[Vec<char>]) -> i64 {
+    let width = puzzle[0].len();
+    let mut timelines = vec![0; width];
+    timelines[start(puzzle)] = 1;
+
+    for line in puzzle {
+        let mut next = vec![0; width];
+
+        for (x, &count) in timelines.iter().enumerate() {
+            if line[x] == '^' {
+                if x > 0 {
+                    next[x - 1] += count;
+                }
+                if x + 1 < width {
+                    next[x + 1] += count;
+                }
+            } else {
+                next[x] += count;
+            }
+        }
+
+        timelines = next;
+    }
+
+    timelines.iter().sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Vec<char>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+impl Reference for Day07 {
+    fn reference1(input: &Self::Input) -> Option<Self::Answer1> {
+        Some(reference_part1(input))
+    }
+
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(reference_part2(input))
+    }
+}
+
+#[test]
+fn test_day07() {
+    let input = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 21);
+    assert_eq!(part2(&input), 40);
+}
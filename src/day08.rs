@@ -0,0 +1,140 @@
+use std::cmp::Reverse;
+
+use crate::Solution;
+use crate::parse::{self, ParseError};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Point(f64, f64, f64);
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    let points: Vec<Point> = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let [x, y, z] = parse::comma_separated(input, l)?;
+
+            Ok(Point(x, y, z))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // part 1 multiplies the sizes of the three largest circuits
+    if points.len() < 3 {
+        return Err(ParseError::eof(input, "at least 3 junction boxes"));
+    }
+
+    Ok(points)
+}
+
+fn dist(p1: &Point, p2: &Point) -> f64 {
+    ((p1.0 - p2.0).powf(2.0)
+        + (p1.1 - p2.1).powf(2.0)
+        + (p1.2 - p2.2).powf(2.0))
+    .sqrt()
+}
+
+pub fn solve(puzzle: &[Point], num: usize) -> usize {
+    let mut dists = Vec::with_capacity((puzzle.len() * puzzle.len()) / 2);
+
+    for (from, p_from) in puzzle.iter().enumerate() {
+        for (to, p_to) in puzzle.iter().enumerate() {
+            if from < to {
+                dists.push((from, to, dist(p_from, p_to)));
+            }
+        }
+    }
+
+    dists.sort_by(|d1, d2| d1.2.partial_cmp(&d2.2).unwrap());
+
+    let mut clusters = puzzle
+        .iter()
+        .map(|p| vec![*p])
+        .enumerate()
+        .collect::<Vec<_>>();
+
+    for (from, to, _dist) in dists.iter().take(num) {
+        let mut to = *to;
+        let mut from = *from;
+
+        let x1 = puzzle[to].0;
+        let x2 = puzzle[from].0;
+
+        while clusters[to].1.is_empty() {
+            to = clusters[to].0;
+        }
+
+        while clusters[from].1.is_empty() {
+            from = clusters[from].0;
+        }
+
+        if from != to {
+            // part2
+            if clusters[from].1.len() + clusters[to].1.len() == puzzle.len() {
+                return (x1 * x2) as usize;
+            }
+
+            let mut placeholder = (from, vec![]);
+            std::mem::swap(&mut placeholder, &mut clusters[to]);
+            clusters[from].1.extend(placeholder.1);
+        }
+    }
+
+    clusters.sort_by_key(|(_, ps)| Reverse(ps.len()));
+
+    // part1
+    clusters[0..3].iter().map(|(_, ps)| ps.len()).product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        solve(input, 1000)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        solve(input, usize::MAX)
+    }
+}
+
+#[test]
+fn test_day08() {
+    let input = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+    let input = parse(input).unwrap();
+
+    assert_eq!(solve(&input, 10), 40);
+    assert_eq!(solve(&input, usize::MAX), 25272);
+
+    let err = parse("1,2,3\n4,5,6\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert!(parse("").is_err());
+}
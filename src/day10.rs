@@ -0,0 +1,218 @@
+use itertools::Itertools;
+
+use z3::SatResult;
+use z3::{Optimize, ast::*};
+
+use crate::Solution;
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<usize>,
+}
+
+impl Machine {
+    fn new() -> Self {
+        Self {
+            lights: vec![],
+            buttons: vec![],
+            joltage: vec![],
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let mut machine = Machine::new();
+            let mut in_button = false;
+            let mut in_joltage = false;
+
+            let mut num = 0;
+            let mut num_start = 0;
+
+            // buttons may only be wired to existing lights
+            let wire = |machine: &Machine, num: usize, start: usize| {
+                if num < machine.lights.len() {
+                    Ok(num)
+                } else {
+                    Err(ParseError::at(input, &l[start..], "index of a light"))
+                }
+            };
+
+            for (idx, c) in l.char_indices() {
+                match c {
+                    '.' => {
+                        machine.lights.push(false);
+                    }
+                    '#' => {
+                        machine.lights.push(true);
+                    }
+                    '(' => {
+                        machine.buttons.push(vec![]);
+                        in_button = true;
+                        num_start = idx + 1;
+                    }
+                    '{' => {
+                        in_joltage = true;
+                        num_start = idx + 1;
+                    }
+                    ')' => {
+                        let light = wire(&machine, num, num_start)?;
+                        if let Some(b) = machine.buttons.last_mut() {
+                            b.push(light);
+                        }
+                        num = 0;
+                        in_button = false;
+                    }
+                    ',' => {
+                        if in_button {
+                            let light = wire(&machine, num, num_start)?;
+                            if let Some(b) = machine.buttons.last_mut() {
+                                b.push(light);
+                            }
+                        } else if in_joltage {
+                            machine.joltage.push(num);
+                        }
+                        num = 0;
+                        num_start = idx + 1;
+                    }
+                    '}' => {
+                        machine.joltage.push(num);
+                        num = 0;
+                        in_joltage = false;
+                    }
+                    c if c.is_ascii_digit() => {
+                        num = num * 10 + c.to_digit(10).unwrap() as usize;
+                    }
+                    ' ' | '[' | ']' => continue,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            &l[idx..],
+                            "light, button or joltage",
+                        ));
+                    }
+                }
+            }
+
+            Ok(machine)
+        })
+        .collect()
+}
+
+fn toggle_lights(lights: &mut [bool], button: &[usize]) {
+    for &wire in button {
+        lights[wire] = !lights[wire];
+    }
+}
+
+pub fn part1(puzzle: &Vec<Machine>) -> usize {
+    let mut res = 0;
+
+    for machine in puzzle {
+        'outer: for c in 1.. {
+            for comb in machine.buttons.iter().combinations_with_replacement(c)
+            {
+                let mut lights = vec![false; machine.lights.len()];
+                for button in &comb {
+                    toggle_lights(&mut lights, button);
+                }
+
+                if machine.lights == lights {
+                    res += c;
+                    break 'outer;
+                }
+            }
+        }
+    }
+
+    res
+}
+
+// Let the theorem prover do its magic.
+pub fn part2(puzzle: &Vec<Machine>) -> u64 {
+    let mut res = 0;
+
+    for machine in puzzle {
+        let opt = Optimize::new();
+
+        let button_vars = machine
+            .buttons
+            .iter()
+            .enumerate()
+            .map(|(idx, _)| Int::new_const(format!("b_{idx}")))
+            .collect::<Vec<_>>();
+
+        for (idx, &j) in machine.joltage.iter().enumerate() {
+            let limit = Int::from_u64(j as u64);
+            let mut sum = Int::from_u64(0);
+
+            for (b_idx, b) in machine.buttons.iter().enumerate() {
+                if b.contains(&idx) {
+                    sum += &button_vars[b_idx];
+                }
+            }
+
+            opt.assert(&sum.eq(&limit));
+        }
+
+        let mut button_sum = Int::from_u64(0);
+        for bvar in &button_vars {
+            opt.assert(&bvar.ge(Int::from_u64(0)));
+            button_sum += bvar;
+        }
+
+        opt.minimize(&button_sum);
+
+        if let SatResult::Sat = opt.check(&[]) {
+            let model = opt.get_model().unwrap();
+
+            for b in &button_vars {
+                res += model.get_const_interp(b).unwrap().as_u64().unwrap_or(0);
+            }
+
+            // println!("{}", opt.get_model().unwrap());
+        }
+    }
+
+    res
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_day10() {
+    let input = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 7);
+    assert_eq!(part2(&input), 33);
+}
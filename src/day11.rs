@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Solution;
+use crate::parse::ParseError;
+
+pub fn parse(input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let mut outputs = HashMap::new();
+
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let Some((from, to)) = line.split_once(':') else {
+            return Err(ParseError::at(input, &line[line.len()..], "`:`"));
+        };
+
+        outputs.insert(from, to.split_ascii_whitespace().collect::<Vec<_>>());
+    }
+
+    // every output has to be a device with a line of its own, or `out`
+    for (_, to) in input.lines().filter_map(|l| l.split_once(':')) {
+        if let Some(device) = to
+            .split_ascii_whitespace()
+            .find(|&d| d != "out" && !outputs.contains_key(d))
+        {
+            return Err(ParseError::at(input, device, "defined device"));
+        }
+    }
+
+    if !outputs.contains_key("you") && !outputs.contains_key("svr") {
+        return Err(ParseError::eof(input, "`you` or `svr`"));
+    }
+
+    // the walks of both parts would never end
+    if let Some(device) = cycle(input, &outputs) {
+        return Err(ParseError::at(input, device, "device not on a cycle"));
+    }
+
+    Ok(outputs
+        .into_iter()
+        .map(|(from, to)| {
+            (
+                from.to_owned(),
+                to.into_iter().map(|s| s.to_owned()).collect(),
+            )
+        })
+        .collect())
+}
+
+/// An output leading back to a device it is reachable from, if there is one.
+fn cycle<'a>(
+    input: &'a str,
+    outputs: &HashMap<&'a str, Vec<&'a str>>,
+) -> Option<&'a str> {
+    let mut done = HashSet::new();
+    let mut path = HashSet::new();
+
+    for (start, _) in input.lines().filter_map(|l| l.split_once(':')) {
+        if !done.insert(start) {
+            continue;
+        }
+
+        // devices on the path with the index of their next output to visit
+        let mut stack = vec![(start, 0)];
+        path.insert(start);
+
+        while let Some((device, next)) = stack.last_mut() {
+            let device = *device;
+
+            let Some(&to) = outputs[device].get(*next) else {
+                path.remove(device);
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+
+            if path.contains(to) {
+                return Some(to);
+            } else if outputs.contains_key(to) && done.insert(to) {
+                path.insert(to);
+                stack.push((to, 0));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn part1(puzzle: &HashMap<String, Vec<String>>) -> i64 {
+    fn walk(puzzle: &HashMap<String, Vec<String>>, curr: &str) -> i64 {
+        if curr == "out" {
+            1
+        } else {
+            puzzle
+                .get(curr)
+                .unwrap()
+                .iter()
+                .map(|n| walk(puzzle, n))
+                .sum()
+        }
+    }
+
+    // the examples of the two parts only define one of the start devices
+    if !puzzle.contains_key("you") {
+        return 0;
+    }
+
+    walk(puzzle, "you")
+}
+
+pub fn part2(puzzle: &HashMap<String, Vec<String>>) -> i64 {
+    let mut cache = HashMap::new();
+
+    fn walk<'a>(
+        puzzle: &'a HashMap<String, Vec<String>>,
+        curr: &'a str,
+        mut seen: i64,
+        cache: &mut HashMap<(i64, &'a str), i64>,
+    ) -> i64 {
+        if curr == "fft" || curr == "dac" {
+            seen += 1;
+        }
+
+        let res = if curr == "out" {
+            if seen == 2 { 1 } else { 0 }
+        } else {
+            if let Some(hit) = cache.get(&(seen, curr)) {
+                return *hit;
+            }
+
+            puzzle
+                .get(curr)
+                .unwrap()
+                .iter()
+                .map(|n| walk(puzzle, n, seen, cache))
+                .sum()
+        };
+
+        cache.insert((seen, curr), res);
+
+        res
+    }
+
+    if !puzzle.contains_key("svr") {
+        return 0;
+    }
+
+    walk(puzzle, "svr", 0, &mut cache)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<String, Vec<String>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_day11() {
+    let input1 = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+    let input1 = parse(input1).unwrap();
+
+    assert_eq!(part1(&input1), 5);
+
+    let input2 = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+    let input2 = parse(input2).unwrap();
+
+    assert_eq!(part2(&input2), 2);
+    assert_eq!(part1(&input2), 0);
+
+    let err = parse("you: aaa\naaa: bbb out\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected),
+        (2, 6, "defined device")
+    );
+    let err = parse("aaa: out\n").unwrap_err();
+    assert_eq!((err.line, err.expected), (2, "`you` or `svr`"));
+    let err = parse("you: aaa\naaa: bbb\nbbb: out you\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected),
+        (3, 10, "device not on a cycle")
+    );
+}
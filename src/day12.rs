@@ -0,0 +1,325 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::parse::{self, ParseError};
+use crate::{NoAnswer, Solution};
+
+const SHAPE_SIZE: usize = 3;
+type Shape = [[bool; SHAPE_SIZE]; SHAPE_SIZE];
+const EMPTY_SHAPE: Shape = [[false; SHAPE_SIZE]; SHAPE_SIZE];
+
+#[derive(Debug)]
+pub struct Puzzle {
+    shapes: Vec<Shape>,
+    regions: Vec<(usize, usize, Vec<usize>)>,
+}
+
+fn parse_shape(input: &str, section: &str) -> Result<Shape, ParseError> {
+    let mut shape = EMPTY_SHAPE;
+    let mut lines = section.lines().skip(1);
+
+    for row in shape.iter_mut() {
+        let line = lines.next().ok_or_else(|| {
+            ParseError::at(input, &section[section.len()..], "`#` or `.`")
+        })?;
+        let mut pixels = line.char_indices();
+
+        for pixel in row.iter_mut() {
+            *pixel = match pixels.next() {
+                Some((_, '#')) => true,
+                Some((_, '.')) => false,
+                Some((idx, _)) => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[idx..],
+                        "`#` or `.`",
+                    ));
+                }
+                None => {
+                    let end = &line[line.len()..];
+                    return Err(ParseError::at(input, end, "`#` or `.`"));
+                }
+            };
+        }
+
+        if let Some((idx, _)) = pixels.next() {
+            return Err(ParseError::at(input, &line[idx..], "end of line"));
+        }
+    }
+
+    if let Some(line) = lines.next() {
+        return Err(ParseError::at(input, line, "empty line"));
+    }
+
+    Ok(shape)
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let mut sections = input.split("\n\n").peekable();
+    let mut shapes = vec![];
+    let mut regions = vec![];
+
+    while let Some(section) = sections.next() {
+        if sections.peek().is_some() {
+            shapes.push(parse_shape(input, section)?);
+        } else {
+            for line in section.lines().filter(|l| !l.is_empty()) {
+                let (size, quantities) =
+                    line.split_once(':').ok_or_else(|| {
+                        ParseError::at(input, &line[line.len()..], "`:`")
+                    })?;
+                let (width, height) =
+                    size.split_once('x').ok_or_else(|| {
+                        ParseError::at(input, &size[size.len()..], "`x`")
+                    })?;
+                let indexes: Vec<usize> = quantities
+                    .split_ascii_whitespace()
+                    .map(|n| parse::number(input, n, "number"))
+                    .collect::<Result<_, _>>()?;
+
+                // one quantity per shape
+                if indexes.is_empty() {
+                    return Err(ParseError::at(input, quantities, "number"));
+                } else if indexes.len() > shapes.len() {
+                    let extra = quantities
+                        .split_ascii_whitespace()
+                        .nth(shapes.len())
+                        .unwrap();
+                    return Err(ParseError::at(input, extra, "end of line"));
+                }
+
+                // shapes are placed inside the region only
+                let side = |side| match parse::number(input, side, "number")? {
+                    n if n >= SHAPE_SIZE => Ok(n),
+                    _ => Err(ParseError::at(input, side, "side of at least 3")),
+                };
+
+                regions.push((side(width)?, side(height)?, indexes));
+            }
+        }
+    }
+
+    Ok(Puzzle { shapes, regions })
+}
+
+fn rotate(shape: &Shape) -> Shape {
+    let mut rotated = EMPTY_SHAPE;
+
+    for y in 0..rotated.len() {
+        for x in 0..rotated[y].len() {
+            rotated[y][x] = shape[rotated[y].len() - x - 1][y];
+        }
+    }
+
+    rotated
+}
+
+fn flip(shape: &Shape) -> Shape {
+    let mut flipped = EMPTY_SHAPE;
+
+    for y in 0..flipped.len() {
+        for x in 0..flipped[y].len() {
+            flipped[y][x] = shape[y][flipped[y].len() - x - 1];
+        }
+    }
+
+    flipped
+}
+
+fn orientations(shape: &Shape) -> FxHashSet<Shape> {
+    let mut res = FxHashSet::default();
+
+    let mut current = *shape;
+    for _ in 0..4 {
+        res.insert(current);
+        current = rotate(&current);
+    }
+
+    let mut flipped = flip(shape);
+    for _ in 0..4 {
+        res.insert(flipped);
+        flipped = rotate(&flipped);
+    }
+
+    res
+}
+
+fn can_place(x: usize, y: usize, shape: &Shape, region: &[Vec<bool>]) -> bool {
+    for (pos_y, shape_row) in shape.iter().enumerate() {
+        for (pos_x, pixel) in shape_row.iter().enumerate() {
+            if *pixel && region[pos_y + y][pos_x + x] {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn toggle_place(x: usize, y: usize, shape: &Shape, region: &mut [Vec<bool>]) {
+    for pos_y in 0..SHAPE_SIZE {
+        for pos_x in 0..SHAPE_SIZE {
+            if shape[pos_y][pos_x] {
+                region[pos_y + y][pos_x + x] = !region[pos_y + y][pos_x + x];
+            }
+        }
+    }
+}
+
+fn shape_size(shape: &Shape) -> usize {
+    shape.iter().flatten().filter(|&&c| c).count()
+}
+
+fn recur(
+    cache: &mut FxHashMap<(usize, Vec<Vec<bool>>), bool>,
+    orientations: &[FxHashSet<Shape>],
+    region: &mut [Vec<bool>],
+    shape: usize,
+    count: usize,
+    rest: &[usize],
+) -> bool {
+    let res = if let Some(hit) = cache.get(&(shape, region.to_vec())) {
+        *hit
+    } else if rest.is_empty() && count == 0 {
+        true
+    } else if count == 0 {
+        recur(cache, orientations, region, shape + 1, rest[0], &rest[1..])
+    } else {
+        // find position/orientation
+        for o in &orientations[shape] {
+            for y in 0..=region.len() - SHAPE_SIZE {
+                for x in 0..=region[0].len() - SHAPE_SIZE {
+                    if can_place(x, y, o, region) {
+                        toggle_place(x, y, o, region);
+
+                        if recur(
+                            cache,
+                            orientations,
+                            region,
+                            shape,
+                            count - 1,
+                            rest,
+                        ) {
+                            return true;
+                        }
+
+                        // undo
+                        toggle_place(x, y, o, region);
+                    }
+                }
+            }
+        }
+
+        false
+    };
+
+    cache.insert((shape, region.to_vec()), res);
+    res
+}
+
+pub fn solve(puzzle: &Puzzle) -> i64 {
+    let mut can_fit = 0;
+
+    let orientations: Vec<_> = puzzle.shapes.iter().map(orientations).collect();
+    let shape_sizes: Vec<usize> =
+        puzzle.shapes.iter().map(shape_size).collect();
+
+    for (width, height, quantities) in &puzzle.regions {
+        let mut cache = FxHashMap::default();
+        let mut region = vec![vec![false; *width]; *height];
+
+        let max_size = region.len() * region[0].len();
+
+        if max_size
+            >= SHAPE_SIZE * SHAPE_SIZE * quantities.iter().sum::<usize>()
+        {
+            can_fit += 1; // will always fit
+        } else if max_size
+            < quantities
+                .iter()
+                .enumerate()
+                .map(|(idx, q)| shape_sizes[idx] * q)
+                .sum::<usize>()
+        {
+            continue; // will never fit
+        } else if recur(
+            &mut cache,
+            &orientations,
+            &mut region,
+            0,
+            quantities[0],
+            &quantities[1..],
+        ) {
+            can_fit += 1;
+        }
+    }
+
+    can_fit
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Puzzle;
+    type Answer1 = i64;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        solve(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        NoAnswer
+    }
+}
+
+#[test]
+fn test_day12() {
+    let input = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2";
+    let input = parse(input).unwrap();
+    assert_eq!(solve(&input), 2);
+
+    let err = parse("0:\n###\n###\n###\n\n3x0: 1\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected),
+        (6, 3, "side of at least 3")
+    );
+}
@@ -0,0 +1,183 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day07::Day07;
+use crate::generator::{self, Generator};
+use crate::minimize;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::runner::Part;
+use crate::{Answer, Solution};
+
+/// Straightforward but slow formulation of a day, used as oracle for the
+/// optimized solver on random inputs.
+pub trait Reference: Solution {
+    /// Reference answer of part 1, `None` if there is none or the input is
+    /// too large for it.
+    fn reference1(input: &Self::Input) -> Option<Self::Answer1>;
+
+    /// Reference answer of part 2, `None` if there is none or the input is
+    /// too large for it.
+    fn reference2(input: &Self::Input) -> Option<Self::Answer2>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: Part,
+    pub expected: String,
+    /// Answer of the solver, `None` if it panicked or returned an error.
+    pub actual: Option<String>,
+}
+
+pub struct Check {
+    pub day: u8,
+    pub generate: Generator,
+    compare: fn(&str) -> Result<Vec<Mismatch>, ParseError>,
+}
+
+impl Check {
+    const fn new<S: Reference>(generate: Generator) -> Self {
+        Self {
+            day: S::DAY,
+            generate,
+            compare: compare::<S>,
+        }
+    }
+
+    /// Runs reference and solver on `input` and returns their disagreements.
+    pub fn compare(&self, input: &str) -> Result<Vec<Mismatch>, ParseError> {
+        (self.compare)(input)
+    }
+}
+
+pub const CHECKS: [Check; 5] = [
+    Check::new::<Day01>(generator::day01::generate),
+    Check::new::<Day02>(generator::day02::generate),
+    Check::new::<Day03>(generator::day03::generate),
+    Check::new::<Day04>(generator::day04::generate),
+    Check::new::<Day07>(generator::day07::generate),
+];
+
+pub fn check(day: u8) -> Option<&'static Check> {
+    CHECKS.iter().find(|c| c.day == day)
+}
+
+fn compare_part<A: Answer>(
+    part: Part,
+    expected: Option<A>,
+    solve: impl FnOnce() -> A,
+) -> Option<Mismatch> {
+    let expected = expected?.render().ok()??;
+    let actual = panic::catch_unwind(AssertUnwindSafe(solve))
+        .ok()
+        .and_then(|answer| answer.render().ok().flatten());
+
+    (actual.as_ref() != Some(&expected)).then_some(Mismatch {
+        part,
+        expected,
+        actual,
+    })
+}
+
+fn compare<S: Reference>(input: &str) -> Result<Vec<Mismatch>, ParseError> {
+    let input = S::parse(input)?;
+
+    Ok([
+        compare_part(Part::One, S::reference1(&input), || S::part1(&input)),
+        compare_part(Part::Two, S::reference2(&input), || S::part2(&input)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect())
+}
+
+#[derive(Clone, Debug)]
+pub struct Failure {
+    pub seed: u64,
+    pub size: usize,
+    /// Smallest failing input found.
+    pub input: String,
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Compares reference and solver on `cases` generated inputs of increasing
+/// size, so the first failure is usually a small one. It is shrunk further
+/// with the minimizer as long as the disagreement persists.
+pub fn run(
+    check: &Check,
+    seed: u64,
+    cases: usize,
+    max_size: usize,
+) -> Result<(), Failure> {
+    for case in 0..cases {
+        let size = case * (max_size + 1) / cases;
+        let seed = seed.wrapping_add(case as u64);
+        let input = (check.generate)(&mut Rng::new(seed), size);
+
+        let mismatches = check.compare(&input).unwrap_or_else(|e| {
+            panic!(
+                "generated input of day {} doesn't parse: {}",
+                check.day,
+                e.snippet()
+            )
+        });
+
+        if !mismatches.is_empty() {
+            return Err(shrink(
+                check,
+                Failure {
+                    seed,
+                    size,
+                    input,
+                    mismatches,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn shrink(check: &Check, mut failure: Failure) -> Failure {
+    let disagrees = |input: &str| {
+        check
+            .compare(input)
+            .is_ok_and(|mismatches| !mismatches.is_empty())
+    };
+
+    failure.input = minimize::minimize(&failure.input, disagrees);
+    failure.mismatches = check.compare(&failure.input).unwrap_or_default();
+
+    failure
+}
+
+#[test]
+fn test_differential() {
+    for check in &CHECKS {
+        assert!(run(check, 2025, 30, 8).is_ok(), "day {}", check.day);
+    }
+
+    // flags every input containing a 9
+    let check = Check {
+        day: 0,
+        generate: generator::day03::generate,
+        compare: |input| {
+            Ok(input
+                .contains('9')
+                .then(|| Mismatch {
+                    part: Part::One,
+                    expected: "0".into(),
+                    actual: None,
+                })
+                .into_iter()
+                .collect())
+        },
+    };
+
+    let failure = run(&check, 1, 10, 5).unwrap_err();
+    assert_eq!(failure.input.lines().count(), 1);
+    assert!(failure.input.contains('9'));
+}
@@ -0,0 +1,56 @@
+use crate::rng::Rng;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+/// Produces a valid random puzzle input. `size` scales the input, its exact
+/// meaning is documented per day.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub fn generator(day: u8) -> Option<Generator> {
+    match day {
+        1 => Some(day01::generate),
+        2 => Some(day02::generate),
+        3 => Some(day03::generate),
+        4 => Some(day04::generate),
+        5 => Some(day05::generate),
+        6 => Some(day06::generate),
+        7 => Some(day07::generate),
+        8 => Some(day08::generate),
+        9 => Some(day09::generate),
+        10 => Some(day10::generate),
+        11 => Some(day11::generate),
+        12 => Some(day12::generate),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_generators() {
+    use crate::runner;
+
+    for day in 1..=12 {
+        let Some(generate) = generator(day) else {
+            continue;
+        };
+
+        for size in [0, 1, 5, 20] {
+            let input = generate(&mut Rng::new(size as u64), size);
+            assert_eq!(input, generate(&mut Rng::new(size as u64), size));
+            assert!(
+                runner::day(day).unwrap().solve(&input, None).is_ok(),
+                "day {day} generated unparsable input:\n{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::rng::Rng;
+
+/// `size + 1` rotations of 1 to 999 clicks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..=size {
+        let dir = if rng.chance(0.5) { 'L' } else { 'R' };
+        input.push_str(&format!("{dir}{}\n", rng.range(1..=999)));
+    }
+
+    input
+}
@@ -0,0 +1,21 @@
+use crate::rng::Rng;
+
+/// `size + 1` ranges of up to 10000 IDs. IDs have up to `10 + size` digits,
+/// capped at 38, so larger sizes leave the 64 bit range.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let max_digits = (10 + size as u64).min(38);
+
+    let ranges: Vec<String> = (0..=size)
+        .map(|_| {
+            let digits = rng.range(1..=max_digits);
+            let begin = (1..digits).fold(rng.range(0..=9) as u128, |n, _| {
+                n * 10 + rng.range(0..=9) as u128
+            });
+            let end = begin + rng.range(0..=10_000) as u128;
+
+            format!("{begin}-{end}")
+        })
+        .collect();
+
+    ranges.join(",") + "\n"
+}
@@ -0,0 +1,17 @@
+use crate::rng::Rng;
+
+/// `size + 1` banks of 12 to `12 + size` batteries.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..=size {
+        let len = 12 + rng.index(size + 1);
+
+        for _ in 0..len {
+            input.push(char::from(b'0' + rng.range(1..=9) as u8));
+        }
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,15 @@
+use crate::rng::Rng;
+
+/// Square grid with `size + 1` rows, about 60% of the cells hold a roll.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..=size {
+        for _ in 0..=size {
+            input.push(if rng.chance(0.6) { '@' } else { '.' });
+        }
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,22 @@
+use crate::rng::Rng;
+
+/// `size + 1` fresh ranges and `size + 1` ingredients, all IDs below
+/// `1000 * (size + 1)`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let max = 1000 * (size as u64 + 1);
+    let mut input = String::new();
+
+    for _ in 0..=size {
+        let begin = rng.range(1..=max);
+        let end = (begin + rng.range(0..=max / 10)).min(max);
+        input.push_str(&format!("{begin}-{end}\n"));
+    }
+
+    input.push('\n');
+
+    for _ in 0..=size {
+        input.push_str(&format!("{}\n", rng.range(1..=max)));
+    }
+
+    input
+}
@@ -0,0 +1,31 @@
+use crate::rng::Rng;
+
+/// `size + 1` problems of two to four numbers with up to four digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rows = 2 + rng.index(3);
+    let mut lines = vec![String::new(); rows + 1];
+
+    for problem in 0..=size {
+        let nums: Vec<String> =
+            (0..rows).map(|_| rng.range(1..=9999).to_string()).collect();
+        let width = nums.iter().map(|n| n.len()).max().unwrap_or(0);
+        let left_aligned = rng.chance(0.5);
+
+        if problem > 0 {
+            lines.iter_mut().for_each(|l| l.push(' '));
+        }
+
+        for (line, num) in lines.iter_mut().zip(&nums) {
+            if left_aligned {
+                line.push_str(&format!("{num:<width$}"));
+            } else {
+                line.push_str(&format!("{num:>width$}"));
+            }
+        }
+
+        let op = if rng.chance(0.5) { '+' } else { '*' };
+        lines[rows].push_str(&format!("{op:<width$}"));
+    }
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,29 @@
+use crate::rng::Rng;
+
+/// Manifold of `2 * size + 3` columns and `2 * size + 2` rows. Splitters are
+/// placed on every other row and never on the outer columns.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let height = 2 * size + 2;
+
+    let mut input = String::new();
+
+    for y in 0..height {
+        let mut line = vec!['.'; width];
+
+        if y == 0 {
+            line[1 + rng.index(width - 2)] = 'S';
+        } else if y % 2 == 0 {
+            for cell in &mut line[1..width - 1] {
+                if rng.chance(0.4) {
+                    *cell = '^';
+                }
+            }
+        }
+
+        input.extend(line);
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,13 @@
+use crate::rng::Rng;
+
+/// `size + 3` junction boxes with coordinates below 100000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size + 3 {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+        input.push_str(&format!("{x},{y},{z}\n"));
+    }
+
+    input
+}
@@ -0,0 +1,42 @@
+use crate::rng::Rng;
+
+/// Simple rectilinear polygon that is monotone in x, made of `size + 2`
+/// columns of varying top and bottom. Adjacent columns always overlap, so the
+/// outline never touches itself.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let columns = size + 2;
+    let step = 10 * columns as u64;
+
+    let mut xs = vec![rng.range(0..=10) as i64];
+    for _ in 0..columns {
+        xs.push(xs[xs.len() - 1] + rng.range(2..=10) as i64);
+    }
+
+    // (bottom, top) of each column, moved above zero at the end
+    let mut spans = vec![(0, rng.range(1..=step) as i64)];
+    while spans.len() < columns {
+        let (prev_bottom, prev_top) = spans[spans.len() - 1];
+
+        let bottom = prev_top - rng.range(1..=step) as i64;
+        let top = bottom.max(prev_bottom) + rng.range(1..=step) as i64;
+
+        // equal heights would merge the corners into a straight edge
+        if bottom != prev_bottom && top != prev_top {
+            spans.push((bottom, top));
+        }
+    }
+
+    let lowest = spans.iter().map(|&(bottom, _)| bottom).min().unwrap_or(0);
+
+    let mut vertices = vec![];
+    for (idx, &(_, top)) in spans.iter().enumerate() {
+        vertices.push((xs[idx], top - lowest));
+        vertices.push((xs[idx + 1], top - lowest));
+    }
+    for (idx, &(bottom, _)) in spans.iter().enumerate().rev() {
+        vertices.push((xs[idx + 1], bottom - lowest));
+        vertices.push((xs[idx], bottom - lowest));
+    }
+
+    vertices.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
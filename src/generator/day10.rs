@@ -0,0 +1,63 @@
+use crate::rng::Rng;
+
+/// `size + 1` machines with three to six lights and two to eight buttons.
+/// Lights and joltages are produced by pressing buttons, so every machine is
+/// solvable.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..=size {
+        let lights = 3 + rng.index(4);
+        let buttons: Vec<Vec<usize>> = (0..2 + rng.index(lights + 1))
+            .map(|_| {
+                let wires: Vec<usize> =
+                    (0..lights).filter(|_| rng.chance(0.4)).collect();
+
+                if wires.is_empty() {
+                    vec![rng.index(lights)]
+                } else {
+                    wires
+                }
+            })
+            .collect();
+
+        let mut target = vec![false; lights];
+        let mut joltage = vec![0; lights];
+
+        for button in &buttons {
+            if rng.chance(0.5) {
+                for &wire in button {
+                    target[wire] = !target[wire];
+                }
+            }
+
+            let presses = rng.range(0..=5) as usize;
+            for &wire in button {
+                joltage[wire] += presses;
+            }
+        }
+
+        let target: String = target
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|b| {
+                let wires: Vec<String> =
+                    b.iter().map(|w| w.to_string()).collect();
+                format!("({})", wires.join(","))
+            })
+            .collect();
+        let joltage: Vec<String> =
+            joltage.iter().map(|j| j.to_string()).collect();
+
+        input.push_str(&format!(
+            "[{target}] {} {{{}}}\n",
+            buttons.join(" "),
+            joltage.join(",")
+        ));
+    }
+
+    input
+}
@@ -0,0 +1,56 @@
+use crate::rng::Rng;
+
+const SPECIAL: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+fn name(mut idx: usize) -> String {
+    let mut name = vec![b'a'; 3];
+    for c in name.iter_mut().rev() {
+        *c = b'a' + (idx % 26) as u8;
+        idx /= 26;
+    }
+
+    String::from_utf8(name).unwrap()
+}
+
+/// Acyclic graph of `size + 6` devices, each connected to the next one and
+/// possibly to another of the following four. `svr` comes first and `out` is
+/// the only device without outputs, so every path ends there. The number of
+/// paths grows exponentially with `size`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size + 6;
+
+    let mut nodes: Vec<String> = (0..)
+        .map(name)
+        .filter(|n| !SPECIAL.contains(&n.as_str()))
+        .take(count - 1)
+        .collect();
+
+    nodes[0] = "svr".to_owned();
+    for special in ["you", "fft", "dac"] {
+        let idx = loop {
+            let idx = 1 + rng.index(count - 2);
+            if !SPECIAL.contains(&nodes[idx].as_str()) {
+                break idx;
+            }
+        };
+        nodes[idx] = special.to_owned();
+    }
+    nodes.push("out".to_owned());
+
+    let mut input = String::new();
+
+    for (idx, node) in nodes.iter().enumerate().take(count - 1) {
+        // the chain through all devices guarantees a path via `fft` and `dac`
+        let mut outputs = vec![nodes[idx + 1].as_str()];
+        if rng.chance(0.5) {
+            let to = &nodes[idx + 1 + rng.index((count - idx - 1).min(4))];
+            if !outputs.contains(&to.as_str()) {
+                outputs.push(to);
+            }
+        }
+
+        input.push_str(&format!("{node}: {}\n", outputs.join(" ")));
+    }
+
+    input
+}
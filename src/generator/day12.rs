@@ -0,0 +1,58 @@
+use crate::rng::Rng;
+
+const SHAPES: usize = 6;
+
+/// Six random shapes and `size + 1` regions of 3 to `5 + size` cells per
+/// side. Large regions either trivially fit their presents or can never fit
+/// them, only small ones require an actual search.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut cells = vec![];
+
+    for idx in 0..SHAPES {
+        let mut shape = [[false; 3]; 3];
+        while shape.iter().flatten().filter(|&&c| c).count() < 5 {
+            shape[rng.index(3)][rng.index(3)] = true;
+        }
+        cells.push(shape.iter().flatten().filter(|&&c| c).count());
+
+        input.push_str(&format!("{idx}:\n"));
+        for row in shape {
+            input.extend(row.map(|c| if c { '#' } else { '.' }));
+            input.push('\n');
+        }
+        input.push('\n');
+    }
+
+    for _ in 0..=size {
+        let width = 3 + rng.index(size + 3);
+        let height = 3 + rng.index(size + 3);
+        let mut quantities = [0; SHAPES];
+
+        if width * height <= 25 {
+            for _ in 0..1 + rng.index(3) {
+                quantities[rng.index(SHAPES)] += 1;
+            }
+        } else if rng.chance(0.5) {
+            // fits without overlapping bounding boxes
+            for _ in 0..rng.index((width / 3) * (height / 3) + 1) {
+                quantities[rng.index(SHAPES)] += 1;
+            }
+        } else {
+            // more occupied cells than the region has
+            let mut occupied = 0;
+            while occupied <= width * height {
+                let shape = rng.index(SHAPES);
+                quantities[shape] += 1;
+                occupied += cells[shape];
+            }
+        }
+
+        let quantities: Vec<String> =
+            quantities.iter().map(|q| q.to_string()).collect();
+        input
+            .push_str(&format!("{width}x{height}: {}\n", quantities.join(" ")));
+    }
+
+    input
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the default input directory.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_DIR: &str = "input";
+
+/// Where to read the puzzle input from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    /// `inputNN.txt` inside the given directory.
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interprets a command line argument, `-` meaning stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(arg.into())
+        }
+    }
+
+    /// Input directory from `AOC_INPUT_DIR`, falling back to `input/`.
+    pub fn default_dir() -> Self {
+        Source::Dir(dir())
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let res = match self {
+            Source::Dir(dir) => fs::read_to_string(path(dir, day)),
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        res.map_err(|error| InputError {
+            day,
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+pub fn dir() -> PathBuf {
+    std::env::var_os(DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DIR))
+}
+
+pub fn path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("input{day:02}.txt"))
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot read input of day {:02} from ", self.day)?;
+
+        match &self.source {
+            Source::Dir(dir) => write!(f, "{}", path(dir, self.day).display())?,
+            Source::File(path) => write!(f, "{}", path.display())?,
+            Source::Stdin => write!(f, "stdin")?,
+        }
+
+        write!(f, ": {}", self.error)?;
+
+        if let (Source::Dir(_), io::ErrorKind::NotFound) =
+            (&self.source, self.error.kind())
+        {
+            write!(
+                f,
+                " (pass the input path or set {DIR_VAR} to its directory)"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[test]
+fn test_input() {
+    assert_eq!(
+        path(Path::new("input"), 7),
+        PathBuf::from("input/input07.txt")
+    );
+    assert_eq!(Source::from_arg("-"), Source::Stdin);
+
+    let err = Source::Dir("does/not/exist".into()).read(3).unwrap_err();
+    assert_eq!(err.error.kind(), io::ErrorKind::NotFound);
+    assert!(
+        err.to_string()
+            .starts_with("cannot read input of day 03 from does/not/exist")
+    );
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+
+use input::Source;
+use parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod differential;
+pub mod generator;
+pub mod input;
+pub mod minimize;
+pub mod parse;
+pub mod rng;
+pub mod runner;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+/// Common interface of all puzzle days.
+pub trait Solution {
+    /// Day of the puzzle, starting at 1.
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Anything that can be printed as a puzzle answer.
+pub trait Answer {
+    /// The printed answer, `None` if the part doesn't exist and an error
+    /// message if it couldn't be computed.
+    fn render(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> Answer for T {
+    fn render(&self) -> Result<Option<String>, String> {
+        Ok(Some(self.to_string()))
+    }
+}
+
+/// Answer of a part that doesn't exist, e.g. the second part of day 12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Answer for NoAnswer {
+    fn render(&self) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+}
+
+/// 64 bit FNV-1a hash. Unlike `DefaultHasher` it is stable across Rust
+/// releases, so it can be written to files.
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Prints the errors of the parts that couldn't be solved and returns whether
+/// there were none.
+fn report_errors(run: &runner::Run) -> bool {
+    for e in &run.errors {
+        eprintln!("error: part{}: {}", e.part.number(), e.message);
+    }
+
+    run.errors.is_empty()
+}
+
+/// Parses `input` and prints the answers of both parts. Returns whether all
+/// parts could be solved.
+pub fn run<S: Solution>(input: &str) -> Result<bool, ParseError> {
+    let run = runner::solve::<S>(input, None)?;
+
+    for p in &run.parts {
+        println!("part{} = {}", p.part.number(), p.answer);
+    }
+
+    Ok(report_errors(&run))
+}
+
+/// Parses `input` and prints answers, timings and input hash of both parts as
+/// JSON. Returns whether all parts could be solved.
+pub fn report<S: Solution>(input: &str) -> Result<bool, ParseError> {
+    let run = runner::solve::<S>(input, None)?;
+    let reports = run.reports(S::DAY, input);
+    println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+
+    Ok(report_errors(&run))
+}
+
+/// Entry point of the `dayNN` binaries. The input is read from the path given
+/// as argument (`-` for stdin) or from the input directory. With `--json` the
+/// answers are printed as JSON.
+pub fn main<S: Solution>() -> ExitCode {
+    let mut source = Source::default_dir();
+    let mut json = false;
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            path => source = Source::from_arg(path),
+        }
+    }
+
+    let input = match source.read(S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let res = if json {
+        report::<S>(&input)
+    } else {
+        run::<S>(&input)
+    };
+
+    match res {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e.snippet());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_fnv1a() {
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+}
@@ -0,0 +1,299 @@
+use std::env;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::differential;
+use crate::runner::{Day, Part};
+
+/// The kind of failure an input has to keep reproducing while it is shrunk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Predicate {
+    /// The solver panics.
+    Panic,
+    /// The solver disagrees with the reference implementation.
+    Mismatch,
+    /// Parsing and solving take longer than the limit. Each attempt runs
+    /// `aoc run` in a child process, which is killed once the limit is
+    /// reached, so hanging inputs are found as well. Only usable from the `aoc`
+    /// binary, which is re-executed for this.
+    Timeout(Duration),
+}
+
+impl Predicate {
+    /// Whether `input` makes the selected parts of `day` fail. Inputs that
+    /// don't parse never do.
+    pub fn holds(&self, day: &Day, part: Option<Part>, input: &str) -> bool {
+        match self {
+            Predicate::Panic => {
+                panic::catch_unwind(AssertUnwindSafe(|| day.solve(input, part)))
+                    .is_err()
+            }
+            Predicate::Mismatch => differential::check(day.number)
+                .and_then(|check| check.compare(input).ok())
+                .is_some_and(|mismatches| {
+                    mismatches
+                        .iter()
+                        .any(|m| part.is_none_or(|part| part == m.part))
+                }),
+            Predicate::Timeout(limit) => {
+                let Ok(aoc) = env::current_exe() else {
+                    return false;
+                };
+
+                let mut command = Command::new(aoc);
+                command.args(["run", &day.number.to_string(), "-i", "-"]);
+                if let Some(part) = part {
+                    command.args(["-p", &part.number().to_string()]);
+                }
+
+                times_out(&mut command, input, *limit)
+            }
+        }
+    }
+}
+
+/// Whether `command`, given `input` on stdin, is still running after
+/// `limit`. It is killed then, so no attempt outlives the check.
+fn times_out(command: &mut Command, input: &str, limit: Duration) -> bool {
+    let Ok(mut child) = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return false;
+    };
+
+    let start = Instant::now();
+    let mut stdin = child.stdin.take().unwrap();
+
+    thread::scope(|s| {
+        // ends with an error at the latest when the child is killed
+        s.spawn(move || stdin.write_all(input.as_bytes()));
+
+        loop {
+            match child.try_wait() {
+                Ok(None) if start.elapsed() < limit => {
+                    thread::sleep(Duration::from_millis(1))
+                }
+                Ok(None) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return true;
+                }
+                _ => return false,
+            }
+        }
+    })
+}
+
+/// Delta debugging: removes ever smaller chunks of `units` as long as the
+/// remainder still fails.
+fn ddmin<T: Clone>(
+    mut units: Vec<T>,
+    mut fails: impl FnMut(&[T]) -> bool,
+) -> Vec<T> {
+    let mut chunks = 2;
+
+    while units.len() >= 2 {
+        let size = units.len().div_ceil(chunks);
+
+        let smaller = (0..units.len()).step_by(size).find_map(|start| {
+            let rest: Vec<T> = units[..start]
+                .iter()
+                .chain(units.iter().skip(start + size))
+                .cloned()
+                .collect();
+
+            fails(&rest).then_some(rest)
+        });
+
+        match smaller {
+            Some(rest) => {
+                units = rest;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks < units.len() => {
+                chunks = (chunks * 2).min(units.len());
+            }
+            None => break,
+        }
+    }
+
+    units
+}
+
+fn lines(input: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let join = |lines: &[&str]| -> String {
+        lines.iter().map(|l| format!("{l}\n")).collect()
+    };
+
+    join(&ddmin(lines, |lines| fails(&join(lines))))
+}
+
+/// Removes comma separated items, such as ranges, from lines holding several
+/// of them.
+fn items(input: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let lines: Vec<Vec<&str>> =
+        input.lines().map(|l| l.split(',').collect()).collect();
+
+    let units: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, items)| items.len() > 1)
+        .flat_map(|(line, items)| (0..items.len()).map(move |i| (line, i)))
+        .collect();
+
+    let join = |units: &[(usize, usize)]| -> String {
+        let mut keep: Vec<Vec<bool>> = lines
+            .iter()
+            .map(|items| vec![items.len() == 1; items.len()])
+            .collect();
+        for &(line, i) in units {
+            keep[line][i] = true;
+        }
+
+        lines
+            .iter()
+            .zip(&keep)
+            .map(|(items, keep)| {
+                let kept: Vec<&str> = items
+                    .iter()
+                    .zip(keep)
+                    .filter(|&(_, &keep)| keep)
+                    .map(|(item, _)| *item)
+                    .collect();
+
+                kept.join(",") + "\n"
+            })
+            .collect()
+    };
+
+    join(&ddmin(units, |units| fails(&join(units))))
+}
+
+/// Rows of `input` if it is a rectangular grid.
+fn grid(input: &str) -> Option<Vec<Vec<char>>> {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|l| l.chars().collect()).collect();
+
+    (grid.len() > 1 && grid.iter().all(|row| row.len() == grid[0].len()))
+        .then_some(grid)
+}
+
+fn columns(input: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let Some(grid) = grid(input) else {
+        return input.to_owned();
+    };
+
+    let join = |columns: &[usize]| -> String {
+        grid.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|&c| row[c])
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect()
+    };
+
+    join(&ddmin((0..grid[0].len()).collect(), |columns| {
+        fails(&join(columns))
+    }))
+}
+
+/// Clears grid cells by replacing them with `.`.
+fn cells(input: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let Some(grid) = grid(input) else {
+        return input.to_owned();
+    };
+
+    if !grid.iter().flatten().any(|&c| c == '.') {
+        return input.to_owned();
+    }
+
+    let units: Vec<(usize, usize)> = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &c)| c != '.')
+                .map(move |(x, _)| (y, x))
+        })
+        .collect();
+
+    let join = |units: &[(usize, usize)]| -> String {
+        let mut cleared = vec![vec!['.'; grid[0].len()]; grid.len()];
+        for &(y, x) in units {
+            cleared[y][x] = grid[y][x];
+        }
+
+        cleared
+            .iter()
+            .map(|row| row.iter().chain(['\n'].iter()).collect::<String>())
+            .collect()
+    };
+
+    join(&ddmin(units, |units| fails(&join(units))))
+}
+
+/// Shrinks `input`, which has to fail, until no line, comma separated item,
+/// grid column or grid cell can be removed without the failure disappearing.
+pub fn minimize(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut input = input.to_owned();
+
+    loop {
+        let before = input.clone();
+
+        input = lines(&input, &mut fails);
+        input = items(&input, &mut fails);
+        input = columns(&input, &mut fails);
+        input = cells(&input, &mut fails);
+
+        if input == before {
+            return input;
+        }
+    }
+}
+
+#[test]
+fn test_minimize() {
+    use crate::runner;
+
+    // only the items the predicate looks at are left
+    let input = "1,5,3\n4,2\n7\n";
+    let fails = |input: &str| input.contains('5') && input.contains('7');
+    assert_eq!(minimize(input, fails), "5\n7\n");
+
+    // splitter on the edge of the manifold
+    let input = "\
+..S..
+.....
+..^..
+.....
+.^.^.
+.....
+^....
+.....
+";
+    let day07 = runner::day(7).unwrap();
+    assert!(Predicate::Panic.holds(day07, None, input));
+    assert_eq!(
+        minimize(input, |i| Predicate::Panic.holds(day07, None, i)),
+        "S\n^\n"
+    );
+
+    // slow attempts are killed after the limit
+    let limit = Duration::from_millis(100);
+    assert!(!times_out(&mut Command::new("cat"), "L68\n", limit));
+    let start = Instant::now();
+    assert!(times_out(Command::new("sleep").arg("10"), "", limit));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
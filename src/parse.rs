@@ -0,0 +1,202 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Position and expectation of the first unparsable token in a puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    /// Content of the offending line.
+    pub text: String,
+    pub expected: &'static str,
+}
+
+impl ParseError {
+    /// Error at the start of `at`, which has to be a subslice of `input`.
+    pub fn at(input: &str, at: &str, expected: &'static str) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |pos| offset + pos);
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end].trim_end_matches('\r').into(),
+            expected,
+        }
+    }
+
+    /// Error at the end of the input.
+    pub fn eof(input: &str, expected: &'static str) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    /// Multi-line report pointing at the offending column.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        format!(
+            "{self}\n{margin} |\n{number} | {}\n{margin} | {}^",
+            self.text,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a subslice of `input`.
+pub fn number<T: FromStr>(
+    input: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+/// Parses exactly `N` comma separated numbers from `line`, a subslice of
+/// `input`.
+pub fn comma_separated<T: FromStr, const N: usize>(
+    input: &str,
+    line: &str,
+) -> Result<[T; N], ParseError> {
+    let mut tokens = line.split(',');
+    let mut nums = Vec::with_capacity(N);
+
+    for _ in 0..N {
+        let token = tokens
+            .next()
+            .ok_or_else(|| ParseError::at(input, &line[line.len()..], "`,`"))?;
+        nums.push(number(input, token, "number")?);
+    }
+
+    if let Some(extra) = tokens.next() {
+        // point at the separator in front of the superfluous token
+        let at = extra.as_ptr() as usize - line.as_ptr() as usize - 1;
+        return Err(ParseError::at(input, &line[at..], "end of line"));
+    }
+
+    let Ok(nums) = nums.try_into() else {
+        unreachable!()
+    };
+
+    Ok(nums)
+}
+
+/// Parses a rectangular grid whose cells are all contained in `cells`.
+pub fn grid(
+    input: &str,
+    cells: &[char],
+    expected: &'static str,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    Ok(grid_rows(input, cells, expected)?
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect())
+}
+
+/// Rows of a rectangular grid whose cells are all contained in `cells`, for
+/// grids not stored as characters.
+pub fn grid_rows<'a>(
+    input: &'a str,
+    cells: &[char],
+    expected: &'static str,
+) -> Result<Vec<&'a str>, ParseError> {
+    let mut rows: Vec<&str> = vec![];
+    let mut width = 0;
+
+    // with ASCII cells only, valid lines can be checked without decoding them
+    let ascii = cells.iter().all(char::is_ascii);
+    let mut allowed = [false; 256];
+    for &c in cells.iter().filter(|c| c.is_ascii()) {
+        allowed[c as usize] = true;
+    }
+
+    for line in input.lines() {
+        let invalid = if ascii {
+            line.bytes().position(|b| !allowed[b as usize])
+        } else {
+            line.char_indices()
+                .find(|(_, c)| !cells.contains(c))
+                .map(|(idx, _)| idx)
+        };
+
+        if let Some(idx) = invalid {
+            return Err(ParseError::at(input, &line[idx..], expected));
+        }
+
+        let len = if ascii {
+            line.len()
+        } else {
+            line.chars().count()
+        };
+
+        if rows.is_empty() {
+            width = len;
+        } else if len < width {
+            return Err(ParseError::at(input, &line[line.len()..], expected));
+        } else if len > width {
+            let (idx, _) = line.char_indices().nth(width).unwrap();
+            return Err(ParseError::at(input, &line[idx..], "end of line"));
+        }
+
+        rows.push(line);
+    }
+
+    Ok(rows)
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "R12\nL6x8\nR3";
+    let err = number::<i64>(input, &input[5..8], "number").unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "L6x8"));
+    assert_eq!(
+        err.snippet(),
+        "\
+expected number at line 2, column 2
+  |
+2 | L6x8
+  |  ^"
+    );
+
+    let err = ParseError::eof(input, "`L` or `R`");
+    assert_eq!((err.line, err.column), (3, 3));
+
+    let input = "1,2\n3,4,5";
+    let err = comma_separated::<i64, 2>(input, &input[4..]).unwrap_err();
+    assert_eq!((err.line, err.column, err.expected), (2, 4, "end of line"));
+    assert_eq!(comma_separated(input, &input[..3]), Ok([1, 2]));
+
+    let err = grid("..@\n.@\n", &['.', '@'], "`.` or `@`").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    let err = grid("..@\n.@..\n", &['.', '@'], "`.` or `@`").unwrap_err();
+    assert_eq!((err.line, err.column, err.expected), (2, 4, "end of line"));
+    let err = grid("..@\n.é.\n", &['.', '@'], "`.` or `@`").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(
+        grid("·.\n.·\n", &['.', '·'], "`.` or `·`").unwrap().len(),
+        2
+    );
+}
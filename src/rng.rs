@@ -0,0 +1,64 @@
+use std::ops::RangeInclusive;
+
+/// Small seedable SplitMix64 generator, so generated inputs are reproducible
+/// without depending on the stream of an external crate.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(42);
+    let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    let mut rng = Rng::new(42);
+    assert!(first.iter().all(|&n| n == rng.next_u64()));
+
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        assert!((3..=9).contains(&rng.range(3..=9)));
+        assert!(rng.index(5) < 5);
+    }
+    assert_eq!(rng.range(4..=4), 4);
+    rng.range(0..=u64::MAX);
+}
@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::bench::{self, Timings};
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::parse::ParseError;
+use crate::{Answer, Solution, fnv1a};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Type erased entry point of a single day.
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, Option<Part>) -> Result<Run, ParseError>,
+    bench:
+        fn(&str, Option<Part>, &bench::Config) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Solves the selected part, or both parts if `part` is `None`.
+    pub fn solve(
+        &self,
+        input: &str,
+        part: Option<Part>,
+    ) -> Result<Run, ParseError> {
+        (self.solve)(input, part)
+    }
+
+    /// Times parsing and the selected parts over many runs.
+    pub fn bench(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        config: &bench::Config,
+    ) -> Result<Timings, ParseError> {
+        (self.bench)(input, part, config)
+    }
+}
+
+pub const DAYS: [Day; 12] = [
+    Day::new::<Day01>(),
+    Day::new::<Day02>(),
+    Day::new::<Day03>(),
+    Day::new::<Day04>(),
+    Day::new::<Day05>(),
+    Day::new::<Day06>(),
+    Day::new::<Day07>(),
+    Day::new::<Day08>(),
+    Day::new::<Day09>(),
+    Day::new::<Day10>(),
+    Day::new::<Day11>(),
+    Day::new::<Day12>(),
+];
+
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// A part whose answer couldn't be computed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartError {
+    pub part: Part,
+    pub message: String,
+}
+
+/// Answers and timings of one day. Parts without an answer are left out,
+/// parts that failed are listed in `errors` instead.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+    pub errors: Vec<PartError>,
+}
+
+impl Run {
+    fn push(
+        &mut self,
+        part: Part,
+        answer: Result<Option<String>, String>,
+        time: Duration,
+    ) {
+        match answer {
+            Ok(Some(answer)) => self.parts.push(PartRun { part, answer, time }),
+            Ok(None) => {}
+            Err(message) => self.errors.push(PartError { part, message }),
+        }
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartRun> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    /// One report per part of `day`, which was solved for `input`.
+    pub fn reports(&self, day: u8, input: &str) -> Vec<Report> {
+        let input_hash = format!("{:016x}", fnv1a(input.as_bytes()));
+
+        self.parts
+            .iter()
+            .map(|p| Report {
+                day,
+                part: p.part.number(),
+                answer: p.answer.clone(),
+                parse_time: self.parse,
+                solve_time: p.time,
+                input_hash: input_hash.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Machine readable result of a single part, times are in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(with = "bench::nanos")]
+    pub parse_time: Duration,
+    #[serde(with = "bench::nanos")]
+    pub solve_time: Duration,
+    /// FNV-1a hash of the input in hex, so results of different inputs can be
+    /// told apart.
+    pub input_hash: String,
+}
+
+pub(crate) fn solve<S: Solution>(
+    input: &str,
+    part: Option<Part>,
+) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let mut run = Run {
+        parse,
+        parts: vec![],
+        errors: vec![],
+    };
+
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let answer = S::part1(&input);
+        run.push(Part::One, answer.render(), start.elapsed());
+    }
+
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        let answer = S::part2(&input);
+        run.push(Part::Two, answer.render(), start.elapsed());
+    }
+
+    Ok(run)
+}
+
+#[test]
+fn test_runner() {
+    assert!(
+        DAYS.iter()
+            .enumerate()
+            .all(|(i, d)| d.number as usize == i + 1)
+    );
+
+    let run = day(1).unwrap().solve("L68\nL30\nR48", None).unwrap();
+    assert_eq!(run.part(Part::One).unwrap().answer, "1");
+    assert_eq!(run.part(Part::Two).unwrap().answer, "2");
+
+    let run = day(1).unwrap().solve("L68\nL30\nR48", Some(Part::Two));
+    assert!(run.as_ref().unwrap().part(Part::One).is_none());
+
+    let reports = run.unwrap().reports(1, "L68\nL30\nR48");
+    assert_eq!((reports[0].day, reports[0].part), (1, 2));
+    assert_eq!(reports[0].input_hash, "8b7cfc6a05deb5e0");
+    let json = serde_json::to_value(&reports[0]).unwrap();
+    assert_eq!(json["answer"], "2");
+    assert!(json["solve_time"].is_u64());
+
+    let run = day(2)
+        .unwrap()
+        .solve("0-1000000000000000000000000000000000000", None);
+    let run = run.unwrap();
+    assert!(run.parts.is_empty());
+    assert_eq!(run.errors.len(), 2);
+    assert_eq!(run.errors[0].message, "sum exceeds 128 bits");
+
+    let err = day(1).unwrap().solve("L68\nX30", None).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}